@@ -35,6 +35,16 @@ where
         }
     }
 
+    /// Used to adjust the opacity of both pixels.
+    pub(crate) fn mult_opac(self, i: f64) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+            oa: self.oa * i,
+            ob: self.ob * i,
+        }
+    }
+
     /// Used to adjust the opacity of the first pixel.
     pub(crate) fn mult_opac_a(self, i: f64) -> Self {
         Self {
@@ -53,7 +63,7 @@ impl AAPt<i32> {
         let a = self.a.u32();
         let b = self.b.u32();
 
-        if (a.x < width) & (a.y < height) {
+        if (a.x < width) && (a.y < height) {
             // This is safe because the coordinates have already been checked against the image bounds
             // Invalid opacity values are safe, but may produce weird blending
             unsafe {
@@ -61,7 +71,7 @@ impl AAPt<i32> {
             }
         }
 
-        if (b.x < width) & (b.y < height) {
+        if (b.x < width) && (b.y < height) {
             // This is safe because the coordinates have already been checked against the image bounds
             // Invalid opacity values are safe, but may produce weird blending
             unsafe {
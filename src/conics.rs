@@ -1,11 +1,31 @@
-//! Conic/circular functions: arcs, antialiased arcs, and annuli (filled-donut shapes).
+//! Conic/circular functions: arcs, antialiased arcs, dashed arcs and circles, filled circles,
+//! annuli (filled-donut shapes), ellipses, elliptical arcs, filled ellipses, rotated ellipses,
+//! antialiased ellipses, and antialiased annuli.
 
+mod aa_annulus;
 mod aa_arc;
 mod annulus;
 mod arc;
 mod cir;
+mod dash;
+mod ellipse;
 
-pub use aa_arc::{antialiased_arc, AntialiasedArc};
-pub use annulus::{annulus, pie_slice_filled, thick_arc, thick_circle, Annulus};
-pub use arc::{arc, Arc};
-pub use cir::circle;
+pub use aa_annulus::{
+    antialiased_annulus, antialiased_pie_slice, antialiased_thick_arc, antialiased_thick_circle,
+    AntialiasedAnnulus,
+};
+pub use aa_arc::{antialiased_arc, antialiased_dashed_arc, AntialiasedArc};
+pub use annulus::{
+    annulus, annulus_alpha, pie_slice_filled, pie_slice_filled_alpha, thick_arc, thick_arc_alpha,
+    thick_circle, thick_circle_alpha, Annulus,
+};
+pub use arc::{arc, arc_alpha, dashed_arc, Arc};
+pub use cir::{antialiased_circle_filled, circle, circle_alpha, circle_filled, dashed_circle};
+pub use ellipse::{
+    antialiased_ellipse, antialiased_ellipse_arc, antialiased_rotated_ellipse, AntialiasedEllipse,
+    AntialiasedEllipseArc,
+};
+pub use ellipse::{
+    ellipse, ellipse_arc, ellipse_filled, ellipse_pie_slice_filled, rotated_ellipse, Ellipse,
+    EllipseArc,
+};
@@ -0,0 +1,617 @@
+use crate::{angle, Pt};
+
+/// Draws an antialiased partial annulus (filled donut).
+///
+/// If the angles are floating-point numbers they are interpreted as radians.
+/// Otherwise the angles are interpreted as degrees.  If the start and end
+/// angles are the same a full annulus is drawn.
+///
+/// Both the curved edges and the straight edges along the start and end angles
+/// are antialiased.
+///
+/// See also: [`Draw::antialiased_annulus`](crate::Draw::antialiased_annulus)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_annulus;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let inner_radius = 150.0;
+/// let outer_radius = 190.0;
+/// let center = (200.0, 200.0);
+/// let start = 0; // 0°
+/// let end = 180; // 180°
+///
+/// antialiased_annulus(&mut image, start, end, inner_radius, outer_radius, center, color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either of the radii are negative.
+pub fn antialiased_annulus<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    inner_radius: T,
+    outer_radius: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    AntialiasedAnnulus::new(start_angle, end_angle, inner_radius, outer_radius, center)
+        .draw(image, color);
+}
+
+/// Draws an antialiased arc with a specified thickness.
+///
+/// The arc is centered on `radius`, extending half of `thickness` to either
+/// side.  A `thickness` of 0 or less draws nothing.
+///
+/// Unlike [`thick_arc`](super::thick_arc), which draws whole pixels and takes
+/// an `i16`, `thickness` is the same type as `radius` since the edges of an
+/// antialiased arc can fall anywhere inside of a pixel.
+///
+/// This is just a wrapper around [`AntialiasedAnnulus`] for convenience.
+///
+/// See also: [`Draw::antialiased_thick_arc`](crate::Draw::antialiased_thick_arc)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_thick_arc;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // A 6px wide arc from 0° to 270°
+/// antialiased_thick_arc(&mut image, 0, 270, 180.0, 6.0, (200.0, 200.0), color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+pub fn antialiased_thick_arc<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    thickness: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let (radius, thickness) = (radius.into(), thickness.into());
+    if thickness <= 0.0 {
+        return;
+    }
+
+    let inner_radius = (radius - thickness / 2.0).max(0.0);
+    let outer_radius = radius + thickness / 2.0;
+    let center = Pt::new(center.x().into(), center.y().into());
+
+    AntialiasedAnnulus::new(start_angle, end_angle, inner_radius, outer_radius, center)
+        .draw(image, color);
+}
+
+/// Draws an antialiased pie slice.
+///
+/// Pie slice is drawn from the circle center to a given radius.
+///
+/// This is just a wrapper around [`AntialiasedAnnulus`] for convenience.
+///
+/// See also: [`Draw::antialiased_pie_slice`](crate::Draw::antialiased_pie_slice)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_pie_slice;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // A slice from 0° to 55°
+/// antialiased_pie_slice(&mut image, 0, 55, 180.0, (200.0, 200.0), color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+pub fn antialiased_pie_slice<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let center = Pt::new(center.x().into(), center.y().into());
+
+    AntialiasedAnnulus::new(start_angle, end_angle, 0.0, radius.into(), center).draw(image, color);
+}
+
+/// Draws an antialiased circle with a given thickness.
+///
+/// The circle is centered on `radius`, extending half of `thickness` to either
+/// side.  A `thickness` of 0 or less draws nothing.
+///
+/// See also: [`Draw::antialiased_thick_circle`](crate::Draw::antialiased_thick_circle)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_thick_circle;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // A 6px wide circle
+/// antialiased_thick_circle(&mut image, 180.0, 6.0, (200.0, 200.0), color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+pub fn antialiased_thick_circle<C, T>(
+    image: &mut image::RgbaImage,
+    radius: T,
+    thickness: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    antialiased_thick_arc(image, 0.0, 0.0, radius, thickness, center, color);
+}
+
+/// An antialiased annulus (part of a filled donut shape) from a start angle to
+/// an end angle.
+///
+/// Implements [`Iterator`], returning each pixel along with its coverage
+/// (opacity from 0.0 to 1.0).  Pixels are returned row by row and each pixel
+/// is only returned once, so no pixel is blended more than once.
+///
+/// The coverage of a pixel is found from its distance to the nearest edge of the
+/// annulus: the inner and outer circles or the straight edges along the start
+/// and end angles.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::AntialiasedAnnulus;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let annulus = AntialiasedAnnulus::new(0, 180, 150.0, 190.0, (200.0, 200.0));
+/// annulus.draw(&mut image, color);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct AntialiasedAnnulus {
+    /// Angle covered going counter-clockwise from the start angle.  A full
+    /// annulus uses `PI*2`.
+    sweep: f64,
+    /// Inner radius
+    ri: f64,
+    /// Outer radius
+    ro: f64,
+    /// Center coordinates
+    c: Pt<f64>,
+    /// Direction of the start angle, with the y axis pointing up
+    start_dir: Pt<f64>,
+    /// Direction of the end angle, with the y axis pointing up
+    end_dir: Pt<f64>,
+    /// Smallest x coordinate that is returned
+    x_min: i32,
+    /// Largest x coordinate that is returned
+    x_max: i32,
+    /// Next x coordinate in the current row
+    x: i32,
+    /// Last x coordinate in the current row
+    x_end: i32,
+    /// Current row
+    y: i32,
+    /// Last row
+    y_end: i32,
+    /// The first and last pixels in the current row that are inside of the hole
+    hole: Option<(i32, i32)>,
+}
+
+impl AntialiasedAnnulus {
+    /// Creates a new [`AntialiasedAnnulus`].
+    ///
+    /// If the angles are floating-point numbers they are interpreted as radians.
+    /// Otherwise the angles are interpreted as degrees.
+    ///
+    /// Negative angles are supported as well as angles larger than 360° (or
+    /// larger than`2*PI` for radians).  If the start and end angles are the
+    /// same a full annulus is used.  The radii are swapped if the inner radius
+    /// is larger than the outer radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the radii are negative.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedAnnulus;
+    /// let annulus = AntialiasedAnnulus::new(0, 180, 150.0, 190.0, (200.0, 200.0));
+    /// ```
+    pub fn new<A, P, T>(start: A, end: A, inner_radius: T, outer_radius: T, center: P) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let mut ri = inner_radius.into();
+        let mut ro = outer_radius.into();
+
+        assert!(
+            ri >= 0.0 && ro >= 0.0,
+            "Radii must be non-negative.  inner={ri:.1} outer={ro:.1}"
+        );
+        if ri > ro {
+            std::mem::swap(&mut ri, &mut ro);
+        }
+
+        let start = angle::normalize(start.radians());
+        let mut sweep = angle::normalize(end.radians() - start);
+        if sweep <= crate::TINY {
+            sweep = crate::PI2;
+        }
+
+        let c = Pt::new(center.x().into(), center.y().into());
+        // Pixels further than half a pixel from the outer edge are not covered
+        let y = (c.y() - ro - 0.5).ceil() as i32;
+        let y_end = (c.y() + ro + 0.5).floor() as i32;
+
+        let dir = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            Pt::new(cos, sin)
+        };
+
+        Self {
+            sweep,
+            ri,
+            ro,
+            c,
+            start_dir: dir(start),
+            end_dir: dir(start + sweep),
+            x_min: i32::MIN,
+            x_max: i32::MAX,
+            x: 0,
+            x_end: -1,
+            y: y - 1,
+            y_end,
+            hole: None,
+        }
+    }
+
+    /// Draw the antialiased annulus by blending each of its pixels into the image.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::AntialiasedAnnulus;
+    /// # let bg = Rgba([255, 255, 255, 255]); // white
+    /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+    /// let annulus = AntialiasedAnnulus::new(0, 180, 150.0, 190.0, (200.0, 200.0));
+    /// annulus.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    pub fn draw(mut self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        let (width, height) = image.dimensions();
+
+        // Only visit the rows and columns inside of the image
+        let clamp = |size: u32| i32::try_from(size).unwrap_or(i32::MAX) - 1;
+        self.x_min = 0;
+        self.x_max = clamp(width);
+        self.y = self.y.max(-1);
+        self.y_end = self.y_end.min(clamp(height));
+
+        for (pt, opacity) in self {
+            let pt = pt.u32();
+            if pt.x() < width && pt.y() < height {
+                // This is safe because the coordinates have been checked against the image bounds
+                unsafe {
+                    crate::ops::blend_at_unchecked(image, pt.x(), pt.y(), opacity as f32, color);
+                }
+            }
+        }
+    }
+
+    /// Move to the next row, calculating which pixels are close enough to the annulus.
+    fn next_row(&mut self) {
+        self.y += 1;
+        let dy = self.y as f64 - self.c.y();
+
+        let ro = self.ro + 0.5;
+        let dx = (ro * ro - dy * dy).max(0.0).sqrt();
+        let (mut x0, mut x1) = (self.c.x() - dx, self.c.x() + dx);
+
+        // A slice that is at most half of the annulus is between the start and
+        // end edges, so the row can be cut down to the part near that wedge
+        if self.sweep <= std::f64::consts::PI {
+            let v = -dy;
+            for (k, c) in [
+                (-self.start_dir.y(), self.start_dir.x() * v),
+                (self.end_dir.y(), -self.end_dir.x() * v),
+            ] {
+                // Where `k * u + c`, the distance to the edge's side, is more than
+                // a pixel outside of the slice
+                let u = (-1.0 - c) / k;
+                if k.abs() < f64::EPSILON {
+                    if c < -1.0 {
+                        x1 = x0 - 1.0;
+                    }
+                } else if k > 0.0 {
+                    x0 = x0.max(self.c.x() + u);
+                } else {
+                    x1 = x1.min(self.c.x() + u);
+                }
+            }
+        }
+
+        self.x = (x0.ceil() as i32).max(self.x_min);
+        self.x_end = (x1.floor() as i32).min(self.x_max);
+
+        // Pixels more than half a pixel inside of the inner edge are not covered
+        let ri = self.ri - 0.5;
+        self.hole = if ri > dy.abs() {
+            let dx = (ri * ri - dy * dy).sqrt();
+            let start = (self.c.x() - dx).floor() as i32 + 1;
+            let end = (self.c.x() + dx).ceil() as i32 - 1;
+            (start <= end).then(|| (start, end))
+        } else {
+            None
+        };
+    }
+
+    /// Calculates how much of a pixel is covered by the annulus.
+    fn coverage(&self, x: f64, y: f64) -> f64 {
+        // Local coordinates with the y axis pointing up
+        let (u, v) = (x - self.c.x(), self.c.y() - y);
+        let dist = u.hypot(v);
+
+        // Signed distance to the circular edges, negative inside the annulus
+        let ring = if self.ri > 0.0 {
+            (dist - self.ro).max(self.ri - dist)
+        } else {
+            dist - self.ro
+        };
+
+        // Signed distance to the straight edges
+        let sector = if self.sweep >= crate::PI2 {
+            f64::NEG_INFINITY
+        } else {
+            let (start, end) = (self.start_dir, self.end_dir);
+            let edge = ray_distance(u, v, start).min(ray_distance(u, v, end));
+            // Which side of each edge the point is on
+            let after_start = start.x() * v - start.y() * u >= 0.0;
+            let before_end = u * end.y() - v * end.x() >= 0.0;
+            let inside = if self.sweep <= std::f64::consts::PI {
+                after_start && before_end
+            } else {
+                after_start || before_end
+            };
+            if inside {
+                -edge
+            } else {
+                edge
+            }
+        };
+
+        (0.5 - ring.max(sector)).clamp(0.0, 1.0)
+    }
+}
+
+impl Iterator for AntialiasedAnnulus {
+    type Item = (Pt<i32>, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.x > self.x_end {
+                if self.y >= self.y_end {
+                    return None;
+                }
+                self.next_row();
+                continue;
+            }
+
+            if let Some((start, end)) = self.hole {
+                if self.x == start {
+                    self.x = end + 1;
+                    continue;
+                }
+            }
+
+            let x = self.x;
+            self.x += 1;
+
+            let opacity = self.coverage(x as f64, self.y as f64);
+            if opacity > 0.0 {
+                return Some((Pt::new(x, self.y), opacity));
+            }
+        }
+    }
+}
+
+/// Distance from a point to a ray that starts at the origin and points in the
+/// direction of the unit vector `dir`.
+fn ray_distance(u: f64, v: f64, dir: Pt<f64>) -> f64 {
+    let (dx, dy) = (dir.x(), dir.y());
+    if u * dx + v * dy <= 0.0 {
+        u.hypot(v)
+    } else {
+        (u * dy - v * dx).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RADS;
+
+    #[test]
+    fn annulus_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        antialiased_annulus(
+            &mut image,
+            RADS * 6.2,
+            RADS * 1.5,
+            80.0,
+            180.0,
+            (200.0, 200.0),
+            color,
+        );
+
+        image.save("images/annulus_aa.png")
+    }
+
+    #[test]
+    fn pie_slice_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        antialiased_pie_slice(
+            &mut image,
+            RADS * 0.5,
+            RADS * 1.5,
+            180.0,
+            (200.0, 200.0),
+            color,
+        );
+
+        image.save("images/pie_slice_aa.png")
+    }
+
+    #[test]
+    fn thick_arc_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        antialiased_thick_arc(
+            &mut image,
+            RADS * 6.0,
+            RADS * 1.0,
+            180.0,
+            9.0,
+            (200.0, 200.0),
+            color,
+        );
+
+        image.save("images/thick_arc_aa.png")
+    }
+
+    #[test]
+    fn thick_circle_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        antialiased_thick_circle(&mut image, 180.0, 7.5, (200.5, 200.0), color);
+
+        image.save("images/thick_circle_aa.png")
+    }
+
+    #[test]
+    fn annulus_aa_coverage() {
+        let full: Vec<(Pt<i32>, f64)> =
+            AntialiasedAnnulus::new(0, 0, 20.0, 40.0, (50.0, 50.0)).collect();
+
+        let mut pts: Vec<Pt<i32>> = full.iter().map(|&(pt, _)| pt).collect();
+        let len = pts.len();
+        pts.sort();
+        pts.dedup();
+        assert_eq!(len, pts.len(), "Pixels should only be returned once");
+
+        // Pixels well inside of the annulus are fully covered
+        assert!(full.contains(&(Pt::new(80, 50), 1.0)));
+        assert!(full.contains(&(Pt::new(50, 20), 1.0)));
+
+        // Two slices that meet should add up to full coverage along the seams
+        let halves: Vec<(Pt<i32>, f64)> =
+            AntialiasedAnnulus::new(30, 200, 20.0, 40.0, (50.0, 50.0))
+                .chain(AntialiasedAnnulus::new(200, 30, 20.0, 40.0, (50.0, 50.0)))
+                .collect();
+        for (pt, _) in full.into_iter().filter(|&(_, o)| o >= 1.0) {
+            let sum: f64 = halves
+                .iter()
+                .filter(|(p, _)| *p == pt)
+                .map(|(_, o)| o)
+                .sum();
+            assert!((sum - 1.0).abs() < 0.01, "{pt}: coverage={sum:.3}");
+        }
+    }
+
+    #[test]
+    fn annulus_aa_wedge() {
+        // Cutting the rows down to the wedge leaves out no covered pixels
+        for (start, end) in [
+            (10, 40),
+            (80, 100),
+            (170, 190),
+            (300, 20),
+            (30, 250),
+            (200, 100),
+        ] {
+            let annulus = AntialiasedAnnulus::new(start, end, 8.5, 30.0, (40.3, 40.0));
+            let pts: Vec<_> = annulus.clone().collect();
+
+            let mut count = 0;
+            for y in 0..=80 {
+                for x in 0..=80 {
+                    let opacity = annulus.coverage(f64::from(x), f64::from(y));
+                    if opacity > 0.0 {
+                        count += 1;
+                        assert!(
+                            pts.contains(&(Pt::new(x, y), opacity)),
+                            "{start}-{end}: ({x}, {y})"
+                        );
+                    }
+                }
+            }
+            assert_eq!(count, pts.len());
+        }
+    }
+
+    #[test]
+    fn annulus_aa_clipped() {
+        // Only the part inside of the image is drawn
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((40, 40));
+        let mut expected = image.clone();
+        let annulus = AntialiasedAnnulus::new(170, 190, 1995.0, 2005.0, (2015.0, 25.0));
+
+        for (pt, opacity) in annulus.clone() {
+            if (0..40).contains(&pt.x()) && (0..40).contains(&pt.y()) {
+                crate::ops::blend_at(
+                    &mut expected,
+                    pt.x() as u32,
+                    pt.y() as u32,
+                    opacity as f32,
+                    color,
+                );
+            }
+        }
+        annulus.draw(&mut image, color);
+        assert_eq!(image, expected);
+        assert_ne!(image, crate::test::img::blank((40, 40)));
+    }
+}
@@ -1,5 +1,6 @@
 mod end;
 
+use super::dash::ArcDashes;
 use crate::angle::angle_to_quad;
 use crate::antialias::AAPt;
 use crate::Pt;
@@ -15,7 +16,7 @@ use end::End;
 /// ```
 /// use image::{RgbaImage, Rgba};
 /// use freehand::conics::antialiased_arc;
-
+///
 /// let bg = Rgba([255, 255, 255, 255]); // white
 /// let color = Rgba([255, 0, 0, 255]);
 /// let mut image = RgbaImage::from_pixel(400, 400, bg);
@@ -61,12 +62,70 @@ pub fn antialiased_arc<A, C, T>(
     AntialiasedArc::new(start_angle, end_angle, radius, center).draw(image, color);
 }
 
+/// Draws a dashed antialiased circular arc.
+///
+/// Dashes and the gaps between them are `dash_width` pixels long, measured
+/// along the arc from the start angle.  If the width is 0 then a solid arc is
+/// drawn.
+///
+/// If the angles are floating-point numbers they are interpreted as radians.
+/// Otherwise the angles are interpreted as degrees.
+///
+/// # Panics
+///
+/// Will panic if `radius` is not larger than 0.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_dashed_arc;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_dashed_arc(&mut image, 0, 180, 190, (200, 200), 8, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_dashed_arc`](crate::Draw::antialiased_dashed_arc)
+///
+pub fn antialiased_dashed_arc<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    center: C,
+    dash_width: u16,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let arc = AntialiasedArc::new(start_angle, end_angle, radius, center);
+
+    if dash_width == 0 {
+        arc.draw(image, color);
+        return;
+    }
+
+    let dashes = ArcDashes::new(
+        start_angle.radians(),
+        end_angle.radians(),
+        arc.r,
+        arc.c,
+        dash_width,
+    );
+
+    // Both pixels of a pair are drawn or skipped together
+    for pt in arc.filter(|pt| dashes.on((Pt::<f64>::from(pt.a) + pt.b.into()).div(2.0))) {
+        pt.draw(image, color);
+    }
+}
+
 /// An antialiased arc.  Implements [`Iterator`] and returns coordinates in order from the starting point.
 ///
 /// ```
 /// use image::{RgbaImage, Rgba};
 /// use freehand::conics::AntialiasedArc;
-
+///
 /// let bg = Rgba([255, 255, 255, 255]); // white
 /// let color = Rgba([255, 0, 0, 255]);
 /// let mut image = RgbaImage::from_pixel(400, 400, bg);
@@ -97,7 +156,7 @@ pub struct AntialiasedArc {
     end_quad: u8,
     /// Whether to increment x (true) or y (false) every iteration.  Only used to make forty-five degree edges look nicer
     fast_x: bool,
-    /// Used when start_angle > end_angle and start_quad == end_quad.  This allows it to  loop back around the circle
+    /// Used when `start_angle > end_angle` and `start_quad == end_quad`.  This allows it to  loop back around the circle
     revisit: bool,
     /// Where to stop
     end: End,
@@ -123,7 +182,7 @@ impl AntialiasedArc {
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # use freehand::conics::AntialiasedArc;
-
+    ///
     /// # let bg = Rgba([255, 255, 255, 255]); // white
     /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
     ///
@@ -150,7 +209,7 @@ impl AntialiasedArc {
 
         let start = crate::angle::normalize(start.radians());
         let mut end = crate::angle::normalize(end.radians());
-        if (start - end).abs() <= std::f64::EPSILON {
+        if (start - end).abs() <= f64::EPSILON {
             end = crate::angle::normalize(start - crate::TINY);
         }
 
@@ -162,7 +221,7 @@ impl AntialiasedArc {
     /// ```
     /// # use image::{RgbaImage, Rgba};
     /// # use freehand::conics::AntialiasedArc;
-
+    ///
     /// # let bg = Rgba([255, 255, 255, 255]); // white
     /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
     ///
@@ -268,8 +327,8 @@ impl AntialiasedArc {
 
     /// Check if iteration should end
     fn end(&mut self) -> bool {
-        let last = (self.quad == self.end_quad) & (self.y <= 0.0);
-        if self.revisit & last {
+        let last = (self.quad == self.end_quad) && (self.y <= 0.0);
+        if self.revisit && last {
             self.revisit = false;
             self.reset();
             false
@@ -335,4 +394,32 @@ mod tests {
 
         image.save("images/arc_aa.png")
     }
+
+    #[test]
+    fn dashed_arc_aa() -> Result<(), image::ImageError> {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (r, c) = (50.0, Pt::new(60.0, 60.0));
+
+        let mut solid = crate::test::img::blank((121, 121));
+        let mut dashed = crate::test::img::blank((121, 121));
+        antialiased_arc(&mut solid, 0.0, 5.0, r, c, color);
+        antialiased_dashed_arc(&mut dashed, 0.0, 5.0, r, c, 10, color);
+
+        // Dashed pixels are blended exactly like the solid arc
+        let white = image::Rgba([255, 255, 255, 255]);
+        for (x, y, p) in dashed.enumerate_pixels() {
+            if *p != white {
+                assert_eq!(p, solid.get_pixel(x, y));
+            }
+        }
+
+        // 5px into the first dash and 5px into the first gap
+        let on = Pt::from_radian(5.0 / r, r, c).i32().u32();
+        let off = Pt::from_radian(15.0 / r, r, c).i32().u32();
+        assert_ne!(dashed.get_pixel(on.x(), on.y()), &white);
+        assert_eq!(dashed.get_pixel(off.x(), off.y()), &white);
+        assert_ne!(solid.get_pixel(off.x(), off.y()), &white);
+
+        dashed.save("images/dashed_arc_aa.png")
+    }
 }
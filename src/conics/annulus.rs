@@ -4,6 +4,7 @@ mod pos;
 use edges::Edge;
 use pos::Pos;
 
+use crate::ops::BlendOnce;
 use crate::translate;
 use crate::{angle, Point, Pt};
 
@@ -54,18 +55,19 @@ use crate::{angle, Point, Pt};
 ///
 /// See also: [`Draw::annulus`](crate::Draw::annulus)
 ///
-pub fn annulus<A, C, I>(
+pub fn annulus<A, C, I, T>(
     image: &mut I,
     start_angle: A,
     end_angle: A,
-    inner_radius: i32,
-    outer_radius: i32,
+    inner_radius: T,
+    outer_radius: T,
     center: C,
     color: I::Pixel,
 ) where
     A: crate::Angle,
-    C: Point<i32>,
+    C: Point<T>,
     I: image::GenericImage,
+    T: Into<f64> + Copy,
 {
     Annulus::new(
         start_angle,
@@ -77,6 +79,58 @@ pub fn annulus<A, C, I>(
     .draw(image, color);
 }
 
+/// Draws a partial annulus (filled donut) with a specified opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// If the angles are floating-point numbers they are interpreted as radians.
+/// Otherwise the angles are interpreted as degrees.
+///
+/// # Panics
+///
+/// - Panics if opacity is not in the range `0.0..=1.0`.
+/// - Panics if either of the radii are negative.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::annulus_alpha;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// annulus_alpha(&mut image, 0, 180, 150, 190, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::annulus_alpha`](crate::Draw::annulus_alpha)
+///
+#[allow(clippy::too_many_arguments)]
+pub fn annulus_alpha<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    inner_radius: T,
+    outer_radius: T,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: Point<T>,
+    T: Into<f64> + Copy,
+{
+    check_opacity!(opacity);
+
+    Annulus::new(
+        start_angle,
+        end_angle,
+        inner_radius,
+        outer_radius,
+        center.pt(),
+    )
+    .draw_alpha(image, opacity, color);
+}
+
 /// Draws an arc with a specified thickness.
 ///
 /// This is just a wrapper around [`Annulus`] for convenience.
@@ -96,31 +150,65 @@ pub fn thick_arc<A, C, I>(
     C: Point<i32>,
     I: image::GenericImage,
 {
-    let thickness: i32 = thickness.into();
-    let thickness = thickness - 1;
-
-    if thickness.is_negative() {
-        return;
+    if let Some((inner_radius, outer_radius)) = thick_radii(radius, thickness) {
+        Annulus::new(
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center.pt(),
+        )
+        .draw(image, color);
     }
+}
 
-    let inr = thickness / 2;
-    let otr = thickness - inr;
-
-    let outer_radius = radius + otr;
-    let inner_radius = if (radius - inr).is_negative() {
-        1
-    } else {
-        radius - inr
-    };
+/// Draws an arc with a specified thickness and opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// # Panics
+///
+/// Panics if opacity is not in the range `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::thick_arc_alpha;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// thick_arc_alpha(&mut image, 0, 180, 180, 10, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::thick_arc_alpha`](crate::Draw::thick_arc_alpha)
+///
+#[allow(clippy::too_many_arguments)]
+pub fn thick_arc_alpha<A, C>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    thickness: i16,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+{
+    check_opacity!(opacity);
 
-    Annulus::new(
-        start_angle,
-        end_angle,
-        inner_radius,
-        outer_radius,
-        center.pt(),
-    )
-    .draw(image, color);
+    if let Some((inner_radius, outer_radius)) = thick_radii(radius, thickness) {
+        Annulus::new(
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center.pt(),
+        )
+        .draw_alpha(image, opacity, color);
+    }
 }
 
 /// Draws a pie slice.
@@ -156,6 +244,44 @@ pub fn pie_slice_filled<A, C, I>(
     .draw(image, color);
 }
 
+/// Draws a pie slice with a specified opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// # Panics
+///
+/// Panics if opacity is not in the range `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::pie_slice_filled_alpha;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// pie_slice_filled_alpha(&mut image, 0, 60, 180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::pie_slice_filled_alpha`](crate::Draw::pie_slice_filled_alpha)
+///
+pub fn pie_slice_filled_alpha<A, C>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: i32,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: Point<i32>,
+{
+    check_opacity!(opacity);
+
+    Annulus::new(start_angle, end_angle, 0, radius, center.pt()).draw_alpha(image, opacity, color);
+}
+
 /// Draws a circle with a given thickness.
 ///
 /// Internally this uses [`Annulus`] to calculate points in a single octet and
@@ -168,11 +294,63 @@ where
     C: Point<i32>,
     I: image::GenericImage,
 {
+    thick_circle_spans(radius, thickness, center.pt(), |span| {
+        put_span(span, image, color);
+    });
+}
+
+/// Draws a circle with a given thickness and opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// # Panics
+///
+/// Panics if opacity is not in the range `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::thick_circle_alpha;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// thick_circle_alpha(&mut image, 180, 10, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::thick_circle_alpha`](crate::Draw::thick_circle_alpha)
+///
+pub fn thick_circle_alpha<C>(
+    image: &mut image::RgbaImage,
+    radius: i32,
+    thickness: i16,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    C: Point<i32>,
+{
+    check_opacity!(opacity);
+
+    let c = center.pt();
+    let (_, outer_radius) = match thick_radii(radius, thickness) {
+        Some(radii) => radii,
+        None => return,
+    };
+
+    let mut blend = BlendOnce::around(image, outer_radius as f64, c.into(), opacity, color);
+    thick_circle_spans(radius, thickness, c, |span| blend.span(span));
+}
+
+/// Calculates the inner and outer radii of an arc with a given thickness.
+///
+/// Returns `None` if the thickness is less than 1.
+fn thick_radii(radius: i32, thickness: i16) -> Option<(i32, i32)> {
     let thickness: i32 = thickness.into();
     let thickness = thickness - 1;
 
     if thickness.is_negative() {
-        return;
+        return None;
     }
 
     let inr = thickness / 2;
@@ -185,12 +363,26 @@ where
         radius - inr
     };
 
+    Some((inner_radius, outer_radius))
+}
+
+/// Calculates the spans of a single octant of a thick circle and passes
+/// them to `put` for all octants.
+fn thick_circle_spans<F>(radius: i32, thickness: i16, center: Pt<i32>, mut put: F)
+where
+    F: FnMut((Pt<i32>, Pt<i32>)),
+{
+    let (inner_radius, outer_radius) = match thick_radii(radius, thickness) {
+        Some(radii) => radii,
+        None => return,
+    };
+
     let mut octant = Annulus::new(
         0.0,
         angle::octant_end_angle(1),
         inner_radius,
         outer_radius,
-        center.pt(),
+        center,
     );
 
     loop {
@@ -203,7 +395,7 @@ where
         let (x, y1, y2) = octant.step();
         let (x, y1, y2) = (x, y1.max(x), y2.max(x));
         for oct in 1..=8 {
-            octant.put_line(x, y1, y2, oct, image, color);
+            put(octant.span(x, y1, y2, oct));
         }
     }
 }
@@ -228,6 +420,20 @@ where
 /// let annulus = Annulus::new(start, end, inner_radius, outer_radius, center);
 /// annulus.draw(&mut image, color);
 /// ```
+///
+/// An [`Annulus`] is also an iterator over the spans of pixels that make up
+/// the annulus.  Each span is a row or column of pixels given by its first
+/// and last pixel (inclusive), with the first pixel having the smaller
+/// coordinates.
+///
+/// ```
+/// use freehand::conics::Annulus;
+///
+/// for (a, b) in Annulus::new(0, 180, 150, 190, (200, 200)) {
+///     assert!(a.x() == b.x() || a.y() == b.y());
+///     assert!(a.x() <= b.x() && a.y() <= b.y());
+/// }
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct Annulus {
@@ -238,7 +444,8 @@ pub struct Annulus {
     inr: Pos, // inner arc
     otr: Pos, // outer arc
     x: i32,
-    c: Pt<i32>,
+    c: Pt<f64>,
+    pixel: Pt<i32>, // pixel containing the center
 }
 
 impl Annulus {
@@ -255,6 +462,7 @@ impl Annulus {
     ///
     /// Will panic if either of the radii are negative.
     ///
+    /// The radii and center may be floating-point numbers.
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
@@ -264,20 +472,24 @@ impl Annulus {
     /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
     /// let annulus = Annulus::new(0, 180, 150, 190, (200, 200));
     /// ```
-    pub fn new<A, P>(
+    pub fn new<A, P, T>(
         start_angle: A,
         end_angle: A,
-        mut inner_radius: i32,
-        mut outer_radius: i32,
+        inner_radius: T,
+        outer_radius: T,
         center: P,
     ) -> Self
     where
         A: crate::Angle,
-        P: crate::pt::Point<i32>,
+        P: crate::pt::Point<T>,
+        T: Into<f64> + Copy,
     {
+        let mut inner_radius = inner_radius.into();
+        let mut outer_radius = outer_radius.into();
+
         let start_angle = crate::angle::normalize(start_angle.radians());
         let mut end_angle = crate::angle::normalize(end_angle.radians());
-        if (start_angle - end_angle).abs() <= std::f64::EPSILON {
+        if (start_angle - end_angle).abs() <= f64::EPSILON {
             end_angle = crate::angle::normalize(end_angle - crate::TINY);
         }
 
@@ -297,7 +509,7 @@ impl Annulus {
             cur_end,
             inner_radius,
             outer_radius,
-            center.pt(),
+            Pt::new(center.x().into(), center.y().into()),
         );
         a.end = Edge::blank(end_angle);
         a
@@ -307,7 +519,7 @@ impl Annulus {
     #[allow(clippy::self_named_constructors)]
     /// An internal function used to create a new [`Annulus`].  The `new()` function
     /// should be used externally, which will also normalize angles and check the radii.
-    fn annulus(start_angle: f64, end_angle: f64, ri: i32, ro: i32, c: Pt<i32>) -> Self {
+    fn annulus(start_angle: f64, end_angle: f64, ri: f64, ro: f64, c: Pt<f64>) -> Self {
         let end_oct = angle::angle_to_octant(end_angle);
         let start_oct = angle::angle_to_octant(start_angle);
 
@@ -327,6 +539,18 @@ impl Annulus {
         cur_start.set_slope(inr.x, inr.y, otr.x, otr.y);
         cur_end.set_slope(inr.ex, inr.ey, otr.ex, otr.ey);
 
+        // Iteration ends on the diagonal of a full octant.  The line between
+        // the rounded end points can stray from the diagonal when the center
+        // is between pixels, so the diagonal is used directly.
+        let (iter_end, diagonal) = if start_oct % 2 == 0 {
+            (cur_start.angle, angle::octant_start_angle(start_oct))
+        } else {
+            (cur_end.angle, angle::octant_end_angle(start_oct))
+        };
+        if (iter_end - diagonal).abs() <= crate::TINY {
+            cur_end.set_diagonal(inr.off);
+        }
+
         Self {
             end,
             x: inr.x.min(otr.x),
@@ -336,6 +560,7 @@ impl Annulus {
             cur_start,
             cur_end,
             c,
+            pixel: Pt::new(c.x().floor(), c.y().floor()).i32(),
         }
     }
 
@@ -364,9 +589,9 @@ impl Annulus {
     }
 
     /// Verify radii are not negative and swap if `inner < outer`.
-    fn validate_radii(inner: &mut i32, outer: &mut i32) {
+    fn validate_radii(inner: &mut f64, outer: &mut f64) {
         assert!(
-            !(inner.is_negative() | outer.is_negative()),
+            *inner >= 0.0 && *outer >= 0.0,
             "Radii must be non-negative.  inner={inner} outer={outer}"
         );
 
@@ -470,14 +695,45 @@ impl Annulus {
     /// let annulus = Annulus::new(0, 180, 150, 190, (190, 190));
     /// annulus.draw(&mut image, color);
     /// ```
-    pub fn draw<I>(mut self, image: &mut I, color: I::Pixel)
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
     where
         I: image::GenericImage,
     {
+        for span in self {
+            put_span(span, image, color);
+        }
+    }
+
+    /// Blend the annulus into an image, blending each pixel once.
+    fn draw_alpha(self, image: &mut image::RgbaImage, opacity: f32, color: image::Rgba<u8>) {
+        let mut blend = BlendOnce::around(image, self.otr.r, self.c, opacity, color);
+        for span in self {
+            blend.span(span);
+        }
+    }
+
+    /// Translate a line from the given iterator coordinates into a span of
+    /// image coordinates.
+    fn span(&self, x: i32, yi: i32, yo: i32, oct: u8) -> (Pt<i32>, Pt<i32>) {
+        let a = translate::iter_to_real(x, yi.min(yo), oct, self.pixel);
+        let b = translate::iter_to_real(x, yi.max(yo), oct, self.pixel);
+
+        (
+            Pt::new(a.x().min(b.x()), a.y().min(b.y())),
+            Pt::new(a.x().max(b.x()), a.y().max(b.y())),
+        )
+    }
+}
+
+impl Iterator for Annulus {
+    type Item = (Pt<i32>, Pt<i32>);
+
+    /// Returns the first and last pixels of the next span in image coordinates
+    fn next(&mut self) -> Option<Self::Item> {
         loop {
             if self.stop() {
                 if self.is_end() {
-                    return;
+                    return None;
                 }
                 self.switch_octant();
                 continue;
@@ -485,34 +741,27 @@ impl Annulus {
 
             let (x, y1, y2) = self.step();
 
-            if (self.x >= self.inr.ex && self.x >= self.otr.ex) && (y1 < x || y2 < x) {
+            let diagonal = self.inr.diagonal(x);
+            if (self.x >= self.inr.ex && self.x >= self.otr.ex) && (y1 < diagonal || y2 < diagonal)
+            {
                 continue;
             }
 
-            let (x, y1, y2) = (x, y1.max(x), y2.max(x));
+            let (y1, y2) = (y1.max(diagonal), y2.max(diagonal));
 
-            self.put_line(x, y1, y2, self.oct, image, color);
+            return Some(self.span(x, y1, y2, self.oct));
         }
     }
+}
+
+/// Draw a span of pixels onto an image.
+fn put_span<I: image::GenericImage>(span: (Pt<i32>, Pt<i32>), image: &mut I, color: I::Pixel) {
+    let (a, b) = span;
+    let (width, height) = (image.width(), image.height());
 
-    /// Draw a line from the given iterator coordinates onto an image.
-    fn put_line<I: image::GenericImage>(
-        &self,
-        x: i32,
-        yi: i32,
-        yo: i32,
-        oct: u8,
-        image: &mut I,
-        color: I::Pixel,
-    ) {
-        let width = image.width();
-        let height = image.height();
-
-        let min = yo.min(yi);
-        let max = yo.max(yi);
-
-        for y in min..=max {
-            let Pt { x, y } = translate::iter_to_real(x, y, oct, self.c).u32();
+    for y in a.y()..=b.y() {
+        for x in a.x()..=b.x() {
+            let Pt { x, y } = Pt::new(x, y).u32();
             if x < width && y < height {
                 image.put_pixel(x, y, color);
             }
@@ -583,6 +832,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn annulus_sub_pixel() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (ri, ro, c) = (6.3, 12.8, (20.5, 19.25));
+        let mut image = crate::test::img::blank((41, 41));
+
+        super::annulus(&mut image, 0, 360, ri, ro, c, color);
+
+        for (x, y, &p) in image.enumerate_pixels() {
+            let d = (x as f64 - c.0).hypot(y as f64 - c.1);
+            if d >= ri + 0.5 && d <= ro - 0.5 {
+                assert_eq!(p, color, "({x}, {y}) was not drawn");
+            } else if d < ri - 0.75 || d > ro + 0.75 {
+                assert_ne!(p, color, "({x}, {y}) should not be drawn");
+            }
+        }
+    }
+
+    #[test]
+    fn annulus_spans() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let annulus = Annulus::new(20, 250, 12.5, 25.0, (30.0, 30.0));
+
+        let mut drawn = crate::test::img::blank((61, 61));
+        annulus.clone().draw(&mut drawn, color);
+
+        let mut image = crate::test::img::blank((61, 61));
+        for (a, b) in annulus {
+            assert!(
+                a.x() == b.x() || a.y() == b.y(),
+                "{a:?} {b:?} is not a span"
+            );
+            assert!(a.x() <= b.x() && a.y() <= b.y(), "{a:?} {b:?} is reversed");
+            for y in a.y()..=b.y() {
+                for x in a.x()..=b.x() {
+                    image.put_pixel(x as u32, y as u32, color);
+                }
+            }
+        }
+
+        assert_eq!(image, drawn);
+    }
+
+    #[test]
+    fn annulus_alpha_blends_once() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let blank = || crate::test::img::blank((81, 81));
+
+        let (mut solid, mut blended) = (blank(), blank());
+        super::annulus(&mut solid, 0, 360, 20, 35, (40, 40), color);
+        super::annulus_alpha(&mut blended, 0, 360, 20, 35, (40, 40), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+
+        let (mut solid, mut blended) = (blank(), blank());
+        super::annulus(&mut solid, 30, 300, 10.5, 30.5, (40.5, 40.25), color);
+        super::annulus_alpha(&mut blended, 30, 300, 10.5, 30.5, (40.5, 40.25), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+
+        let (mut solid, mut blended) = (blank(), blank());
+        super::thick_arc(&mut solid, 10, 280, 30, 7, (40, 40), color);
+        super::thick_arc_alpha(&mut blended, 10, 280, 30, 7, (40, 40), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+
+        let (mut solid, mut blended) = (blank(), blank());
+        super::pie_slice_filled(&mut solid, 100, 30, 35, (40, 40), color);
+        super::pie_slice_filled_alpha(&mut blended, 100, 30, 35, (40, 40), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+
+        let (mut solid, mut blended) = (blank(), blank());
+        super::thick_circle(&mut solid, 30, 6, (40, 40), color);
+        super::thick_circle_alpha(&mut blended, 30, 6, (40, 40), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+    }
+
     #[test]
     fn pie_slice() -> Result<(), image::ImageError> {
         crate::logger(crate::LOG_LEVEL);
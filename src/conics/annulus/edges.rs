@@ -1,3 +1,5 @@
+use crate::Pt;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
 pub(super) struct Edge {
@@ -23,6 +25,13 @@ impl Edge {
         self.int = (self.slope * (-x1 as f64) + y1 as f64).round();
     }
 
+    /// Follow the diagonal of an octant for a center offset by `off`, rounding
+    /// towards the inside of the octant.
+    pub(super) fn set_diagonal(&mut self, off: Pt<f64>) {
+        self.slope = 1.0;
+        self.int = (off.y() - off.x()).ceil();
+    }
+
     pub(super) fn line(&self) -> (f64, f64) {
         (self.slope, self.int)
     }
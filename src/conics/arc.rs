@@ -2,6 +2,8 @@ mod bounds;
 mod edge;
 mod pos;
 
+use super::dash::ArcDashes;
+use crate::ops::BlendOnce;
 use crate::{angle, translate, Pt};
 use bounds::Bounds;
 use edge::Edge;
@@ -61,15 +63,128 @@ pub fn arc<A, C, I, T>(
     A: crate::Angle,
     C: crate::pt::Point<T>,
     I: image::GenericImage,
-    T: Into<i32> + Copy,
+    T: Into<f64> + Copy,
 {
     Arc::new(start_angle, end_angle, radius, center).draw(image, color);
 }
 
+/// Draws a circular arc with a specified opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// A floating-point angle will represent an angle in radians.  Integer types
+/// will represent an angle in degrees.
+///
+/// # Panics
+///
+/// - Panics if opacity is not in the range `0.0..=1.0`.
+/// - Panics if radius is less than or equal to 0
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::arc_alpha;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// arc_alpha(&mut image, 0, 180, 190, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::arc_alpha`](crate::Draw::arc_alpha)
+///
+pub fn arc_alpha<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    check_opacity!(opacity);
+
+    let arc = Arc::new(start_angle, end_angle, radius, center);
+    let mut blend = BlendOnce::around(image, arc.r, arc.c, opacity, color);
+
+    for pt in arc {
+        blend.blend(pt);
+    }
+}
+
+/// Draws a dashed circular arc from a given start angle to an end angle.
+///
+/// Dashes and the gaps between them are `dash_width` pixels long, measured
+/// along the arc from the start angle.  If the width is 0 then a solid arc is
+/// drawn.
+///
+/// A floating-point angle will represent an angle in radians.  Integer types
+/// will represent an angle in degrees.
+///
+/// # Panics
+///
+/// Panics if radius is less than or equal to 0
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::dashed_arc;
+///
+/// let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// dashed_arc(&mut image, 0, 180, 190, (200, 200), 8, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::dashed_arc`](crate::Draw::dashed_arc)
+///
+pub fn dashed_arc<A, C, I, T>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    radius: T,
+    center: C,
+    dash_width: u16,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<f64> + Copy,
+{
+    let arc = Arc::new(start_angle, end_angle, radius, center);
+
+    if dash_width == 0 {
+        arc.draw(image, color);
+        return;
+    }
+
+    let dashes = ArcDashes::new(
+        start_angle.radians(),
+        end_angle.radians(),
+        arc.r,
+        arc.c,
+        dash_width,
+    );
+
+    for pt in arc.filter(|&pt| dashes.on(pt.into())) {
+        let pt: Result<Pt<u32>, &'static str> = pt.try_into();
+        if let Ok(pt) = pt {
+            if pt.x() < image.width() && pt.y() < image.height() {
+                image.put_pixel(pt.x(), pt.y(), color);
+            }
+        }
+    }
+}
+
 /// A structure for iterating over points in a circular arc.
 ///
-/// Does not implement the `Iterator` trait because points for even octants would
-/// be returned in reverse order.
+/// Points are returned one octant at a time.  Within even octants the points
+/// are returned in reverse order, from the end of the octant back to its start.
 ///
 /// ```
 /// use image::{RgbaImage, Rgba};
@@ -91,6 +206,16 @@ pub fn arc<A, C, I, T>(
 /// /// Draw the struct
 /// arc.draw(&mut image, color);
 /// ```
+///
+/// The points can also be used directly:
+///
+/// ```
+/// use freehand::conics::Arc;
+///
+/// let points: Vec<_> = Arc::new(0, 90, 10, (20, 20)).collect();
+/// assert!(points.contains(&(30, 20).into()));
+/// assert!(points.contains(&(20, 10).into()));
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub struct Arc {
@@ -101,9 +226,9 @@ pub struct Arc {
     /// Angle and octant of the end edge
     end: Edge,
     /// Center of the circular arc
-    c: Pt<i32>,
+    c: Pt<f64>,
     /// Radius of the arc
-    r: i32,
+    r: f64,
     /// Used to determine when to iterate over all octants and back to the original octant.
     /// If `revisit` is true iteration will not immediately end when the octant is finished.
     /// This is set to true for the first octant when `start.oct == end.oct` and `start.angle > end.angle`
@@ -135,7 +260,7 @@ impl Arc {
     pub fn new<A, T, C>(start_angle: A, end_angle: A, radius: T, center: C) -> Self
     where
         A: crate::Angle,
-        T: Into<i32> + Copy,
+        T: Into<f64> + Copy,
         C: crate::pt::Point<T>,
     {
         let start = angle::normalize(start_angle.radians());
@@ -150,19 +275,19 @@ impl Arc {
 
     fn blank<T, C>(start_angle: f64, end_angle: f64, r: T, c: C) -> Self
     where
-        T: Into<i32> + Copy,
+        T: Into<f64> + Copy,
         C: crate::pt::Point<T>,
     {
         let c = Pt::new(c.x().into(), c.y().into());
         let r = r.into();
 
-        assert!(r > 0, "Radius must be larger than 0.  radius={r}");
+        assert!(r > 0.0, "Radius must be larger than 0.  radius={r}");
 
         let start_oct = crate::angle::angle_to_octant(start_angle);
         let end_oct = crate::angle::angle_to_octant(end_angle);
 
         Self {
-            pos: Pos::start(start_oct, r),
+            pos: Pos::start(start_oct, r, c),
             start: Edge::new(start_angle, start_oct),
             end: Edge::new(end_angle, end_oct),
             c,
@@ -180,19 +305,19 @@ impl Arc {
     pub fn octant<T, C>(oct: u8, r: T, c: C) -> Self
     where
         C: crate::pt::Point<T>,
-        T: Into<i32> + Copy,
+        T: Into<f64> + Copy,
     {
         let c = Pt::new(c.x().into(), c.y().into());
         let r = r.into();
 
-        assert!(r > 0, "Radius be must larger than 0");
+        assert!(r > 0.0, "Radius be must larger than 0");
 
         assert!(
             (1..=8).contains(&oct),
             "Invalid octant. Valid octants are 1 through 8"
         );
 
-        let pos = Pos::start(oct, r);
+        let pos = Pos::start(oct, r, c);
 
         let start = Edge::new(angle::octant_start_angle(oct), oct);
         let end = Edge::new(angle::octant_end_angle(oct), oct);
@@ -230,38 +355,29 @@ impl Arc {
     /// arc.draw(&mut image, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    pub fn draw<I>(mut self, image: &mut I, color: I::Pixel)
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
     where
         I: image::GenericImage,
     {
-        loop {
-            if self.pos.stop() {
-                if self.end() {
-                    break;
-                }
-                self.restart();
-                continue;
-            }
-
-            let pt: Result<Pt<u32>, &'static str> = self.pt().try_into();
+        for pt in self {
+            let pt: Result<Pt<u32>, &'static str> = pt.try_into();
             if let Ok(pt) = pt {
                 if pt.x() < image.width() && pt.y() < image.height() {
                     image.put_pixel(pt.x(), pt.y(), color);
                 }
             }
-            self.pos.inc();
         }
     }
 
     /// Helper function to translate the current coordinates into a specified octant
     pub(super) fn coords_oct(&self, oct: u8) -> Pt<i32> {
         let pt = Pt::new(self.pos.x, self.pos.y);
-        translate::iter_to_real(pt.x(), pt.y(), oct, self.c)
+        translate::iter_to_real(pt.x(), pt.y(), oct, self.pos.pixel)
     }
 
     pub(super) fn pt(&self) -> Pt<i32> {
         let pt = Pt::new(self.pos.x, self.pos.y);
-        translate::iter_to_real(pt.x(), pt.y(), self.pos.oct, self.c)
+        translate::iter_to_real(pt.x(), pt.y(), self.pos.oct, self.pos.pixel)
     }
 
     /// Helper function for other modules
@@ -274,19 +390,50 @@ impl Arc {
         self.pos.inc();
     }
 
-    /// Returns the center coordinates
+    /// Returns the center coordinates, rounded to the nearest pixel
     #[must_use]
     pub fn center(&self) -> Pt<i32> {
-        self.c
+        self.c.i32()
     }
 
-    /// Returns the radius
+    /// Returns the radius, rounded to the nearest pixel
     #[must_use]
     pub fn radius(&self) -> i32 {
+        self.r.round() as i32
+    }
+
+    /// Returns the exact center coordinates, which may be between pixels
+    #[must_use]
+    pub fn center_f64(&self) -> Pt<f64> {
+        self.c
+    }
+
+    /// Returns the exact radius
+    #[must_use]
+    pub fn radius_f64(&self) -> f64 {
         self.r
     }
 }
 
+impl Iterator for Arc {
+    type Item = Pt<i32>;
+
+    /// Returns the next point of the arc in image coordinates
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if !self.pos.stop() {
+                let pt = self.pt();
+                self.pos.inc();
+                return Some(pt);
+            }
+            if self.end() {
+                return None;
+            }
+            self.restart();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,4 +455,133 @@ mod tests {
 
         image.save("images/arc.png")
     }
+
+    #[test]
+    fn arc_sub_pixel() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (r, c) = (20.4, (30.5, 29.75));
+        let mut image = crate::test::img::blank((61, 61));
+
+        let arc = Arc::new(10, 170, r, c);
+        assert_eq!(arc.center_f64(), Pt::new(30.5, 29.75));
+        assert!((arc.radius_f64() - r).abs() < f64::EPSILON);
+        assert_eq!(arc.center(), Pt::new(31, 30));
+        assert_eq!(arc.radius(), 20);
+        arc.draw(&mut image, color);
+
+        let mut count = 0;
+        for (x, y, _) in image.enumerate_pixels().filter(|(_, _, &p)| p == color) {
+            let (dx, dy) = (x as f64 - c.0, c.1 - y as f64);
+            assert!(
+                (dx.hypot(dy) - r).abs() < 0.75,
+                "({x}, {y}) is too far from the arc"
+            );
+            assert!(dy > 0.0, "({x}, {y}) is outside of the arc");
+            count += 1;
+        }
+        assert!(count > 40);
+    }
+
+    #[test]
+    fn arc_alpha_blends_once() {
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        for (start, end, r, c) in [
+            (0.0, 6.0, 30.0, (40.0, 40.0)),
+            (1.0, 0.5, 25.5, (40.5, 39.25)),
+            (2.0, 5.0, 7.0, (40.0, 40.0)),
+        ] {
+            let mut solid = crate::test::img::blank((81, 81));
+            let mut blended = crate::test::img::blank((81, 81));
+            super::arc(&mut solid, start, end, r, c, color);
+            super::arc_alpha(&mut blended, start, end, r, c, 0.5, color);
+
+            crate::test::assert_blended_once(&solid, &blended);
+        }
+    }
+
+    #[test]
+    fn arc_iter() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let arc = Arc::new(30, 300, 20, (30, 30));
+
+        let mut drawn = crate::test::img::blank((61, 61));
+        arc.clone().draw(&mut drawn, color);
+
+        let mut image = crate::test::img::blank((61, 61));
+        let points: Vec<Pt<i32>> = arc.collect();
+        crate::draw_iter(&mut image, points.iter().copied(), color);
+
+        assert_eq!(image, drawn);
+        assert!(points.contains(&Pt::new(30, 50)));
+        assert!(!points.contains(&Pt::new(50, 30)));
+    }
+
+    /// Splits the drawn pixels of a dashed arc into dashes and returns the
+    /// length of each dash along the arc.
+    fn dash_lengths(image: &image::RgbaImage, start: f64, r: f64, c: Pt<f64>) -> Vec<f64> {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let mut lengths: Vec<f64> = image
+            .enumerate_pixels()
+            .filter(|(_, _, &p)| p == color)
+            .map(|(x, y, _)| {
+                let a = (c.y() - y as f64).atan2(x as f64 - c.x());
+                let a = angle::normalize(a - start);
+                // Pixels rounded to just before the start
+                let a = if a > crate::PI2 - 0.1 { 0.0 } else { a };
+                a * r
+            })
+            .collect();
+        lengths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut dashes = vec![];
+        let mut first = lengths[0];
+        for pair in lengths.windows(2) {
+            if pair[1] - pair[0] > 2.0 {
+                dashes.push(pair[0] - first);
+                first = pair[1];
+            }
+        }
+        dashes
+    }
+
+    #[test]
+    fn dashed_arc_uniform() -> Result<(), image::ImageError> {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (r, c) = (60.0, Pt::new(80.0, 80.0));
+
+        let mut image = crate::test::img::blank((161, 161));
+        super::dashed_arc(&mut image, 30, 300, r, c, 10, color);
+
+        // Each dash spans across octant boundaries without being cut short
+        let dashes = dash_lengths(&image, 30.0_f64.to_radians(), r, c);
+        assert!(dashes.len() >= 10);
+        for len in dashes {
+            assert!((8.0..=10.5).contains(&len), "dash is {len}px long");
+        }
+
+        image.save("images/dashed_arc.png")
+    }
+
+    #[test]
+    fn dashed_circle_uniform() {
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (r, c) = (40.5, Pt::new(50.0, 50.0));
+
+        let mut image = crate::test::img::blank((101, 101));
+        crate::conics::dashed_circle(&mut image, r, c, 6, color);
+
+        let lengths = dash_lengths(&image, 0.0, r, c);
+        assert!(lengths.len() >= 20);
+        for len in lengths {
+            assert!((4.0..=6.5).contains(&len), "dash is {len}px long");
+        }
+
+        // A dash width of 0 draws a solid circle
+        let mut solid = crate::test::img::blank((101, 101));
+        let mut dashed = crate::test::img::blank((101, 101));
+        crate::conics::circle(&mut solid, r, c, color);
+        crate::conics::dashed_circle(&mut dashed, r, c, 0, color);
+        assert_eq!(solid, dashed);
+    }
 }
@@ -1,57 +1,71 @@
 use super::Bounds;
-use crate::Pt;
+use crate::{translate, Pt};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub(super) struct Pos {
     pub(super) x: i32,
     pub(super) y: i32,
-    pub(super) d: i32,
     pub(super) oct: u8,
     pub(super) ex: Option<i32>,
+    /// Pixel containing the center, used to translate iterator coordinates
+    pub(super) pixel: Pt<i32>,
+    /// Sub-pixel offset of the center in iterator coordinates
+    off: Pt<f64>,
+    /// Radius
+    r: f64,
 }
 
 impl Pos {
-    pub(super) fn new(oct: u8, bounds: Bounds, r: i32, c: Pt<i32>) -> Self {
-        let (Pt { x, y }, d) = match bounds.start {
-            None => (Pt::new(0, r), 1 - r),
-            Some(a) => {
-                let pt = Pt::from_radian(a, r, c).real_to_iter(oct, c.into());
-                (pt.i32(), crate::calc_error(pt, r))
+    pub(super) fn new(oct: u8, bounds: Bounds, r: f64, c: Pt<f64>) -> Self {
+        let (pixel, off) = translate::center_offset(c, oct);
+
+        let Pt { x, y } = match bounds.start {
+            None => {
+                // First column on or after the axis the octant starts from
+                let x = off.x().ceil();
+                let y = (r.powi(2) - (x - off.x()).powi(2)).max(0.0).sqrt() + off.y();
+                Pt::new(x, y).i32()
             }
+            Some(a) => (Pt::from_radian(a, r, c).real_to_iter(oct, c) + off).i32(),
         };
 
-        let ex = bounds.end.map(|a| {
-            Pt::from_radian(a, r, c)
-                .real_to_iter(oct, c.into())
-                .x()
-                .round() as i32
-        });
+        let ex = bounds
+            .end
+            .map(|a| (Pt::from_radian(a, r, c).real_to_iter(oct, c).x() + off.x()).round() as i32);
 
-        Self { x, y, d, oct, ex }
+        Self {
+            x,
+            y,
+            oct,
+            ex,
+            pixel,
+            off,
+            r,
+        }
     }
 
     pub(super) fn stop(&self) -> bool {
-        self.x >= self.ex.map_or(self.y, |ex| ex + 1)
+        let diagonal = || self.x as f64 - self.off.x() - (self.y as f64 - self.off.y());
+        match self.ex {
+            Some(ex) => self.x > ex,
+            // Pixels on the diagonal are left to the odd octant
+            None if self.oct % 2 == 1 => diagonal() > 0.0,
+            None => diagonal() >= 0.0,
+        }
     }
 
-    pub(super) fn start(oct: u8, r: i32) -> Self {
-        Self {
-            x: 0,
-            y: r,
-            d: 1 - r,
-            oct,
-            ex: None,
-        }
+    pub(super) fn start(oct: u8, r: f64, c: Pt<f64>) -> Self {
+        Self::new(oct, Bounds::default(), r, c)
     }
 
     pub(super) fn inc(&mut self) {
+        let pt = Pt::new(self.x as f64, self.y as f64) - self.off;
+        let d = crate::calc_error(pt, self.r);
+
         self.x += 1;
-        if self.d > 0 {
+        if d > 0.0 {
             self.y -= 1;
-            self.d += 2 * (self.x - self.y) + 1;
-        } else {
-            self.d += 2 * self.x + 1;
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::conics;
+use crate::Pt;
 
 /// Draws a full circle.
 ///
@@ -10,8 +11,18 @@ use crate::conics;
 /// circle(&mut image, 380, (200, 200), Rgba([255, 0, 0, 255]));
 /// ```
 ///
+/// The center and radius may be floating-point numbers:
+///
+/// ```
+/// # use freehand::conics::circle;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+/// circle(&mut image, 150.5, (200.5, 199.5), Rgba([255, 0, 0, 255]));
+/// ```
+///
 /// Uses [`conics::Arc`] to calculate a single octant and draw those pixels
-/// in all octants.
+/// in all octants.  Circles centered between pixels are not symmetric around
+/// a pixel, so each octant is calculated separately.
 ///
 /// See also: [`Draw::circle`](crate::Draw::circle)
 ///
@@ -19,8 +30,14 @@ pub fn circle<C, I, T>(image: &mut I, radius: T, center: C, color: I::Pixel)
 where
     C: crate::pt::Point<T>,
     I: image::GenericImage,
-    T: Into<i32> + Copy,
+    T: Into<f64> + Copy,
 {
+    let (cx, cy) = (center.x().into(), center.y().into());
+    if cx.fract() != 0.0 || cy.fract() != 0.0 {
+        conics::Arc::new(0.0, 0.0, radius.into(), (cx, cy)).draw(image, color);
+        return;
+    }
+
     let mut octant = conics::Arc::octant(1, radius, center);
 
     loop {
@@ -46,8 +63,220 @@ where
     }
 }
 
+/// Draws a circle with a specified opacity.
+///
+/// Pixels shared by neighboring octants are only blended once.
+///
+/// ```
+/// use freehand::conics::circle_alpha;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// circle_alpha(&mut image, 180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// - Panics if opacity is not in the range `0.0..=1.0`.
+/// - Panics if radius is less than or equal to 0
+///
+/// See also: [`Draw::circle_alpha`](crate::Draw::circle_alpha)
+///
+pub fn circle_alpha<C, T>(
+    image: &mut image::RgbaImage,
+    radius: T,
+    center: C,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let center = (center.x().into(), center.y().into());
+    conics::arc_alpha(image, 0.0, 0.0, radius.into(), center, opacity, color);
+}
+
+/// Draws a dashed circle.
+///
+/// Dashes and the gaps between them are `dash_width` pixels long, measured
+/// along the circle counter-clockwise from 0°.  If the width is 0 then a solid
+/// circle is drawn.
+///
+/// ```
+/// use freehand::conics::dashed_circle;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// dashed_circle(&mut image, 180, (200, 200), 6, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if radius is less than or equal to 0
+///
+/// See also: [`Draw::dashed_circle`](crate::Draw::dashed_circle)
+///
+pub fn dashed_circle<C, I, T>(image: &mut I, radius: T, center: C, dash_width: u16, color: I::Pixel)
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<f64> + Copy,
+{
+    if dash_width == 0 {
+        circle(image, radius, center, color);
+        return;
+    }
+
+    let center = (center.x().into(), center.y().into());
+    conics::dashed_arc(image, 0.0, 0.0, radius.into(), center, dash_width, color);
+}
+
+/// Draws a filled circle.
+///
+/// Every pixel whose center is within `radius` of `center` is drawn, so the
+/// center and radius may be floating-point numbers.
+///
+/// ```
+/// use freehand::conics::circle_filled;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// circle_filled(&mut image, 5.5, (200.5, 200.5), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+///
+/// See also: [`Draw::circle_filled`](crate::Draw::circle_filled)
+///
+pub fn circle_filled<C, I, T>(image: &mut I, radius: T, center: C, color: I::Pixel)
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<f64> + Copy,
+{
+    let (r, c) = radius_center(radius, center);
+    let (width, height) = (image.width() as i64, image.height() as i64);
+
+    let y1 = ((c.y() - r).ceil() as i64).max(0);
+    let y2 = ((c.y() + r).floor() as i64).min(height - 1);
+
+    for y in y1..=y2 {
+        let dy = y as f64 - c.y();
+        let dx = (r * r - dy * dy).max(0.0).sqrt();
+
+        let x1 = ((c.x() - dx).ceil() as i64).max(0);
+        let x2 = ((c.x() + dx).floor() as i64).min(width - 1);
+
+        for x in x1..=x2 {
+            // Safe because the coordinates have been clamped to the image bounds
+            unsafe {
+                image.unsafe_put_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+}
+
+/// Draws an antialiased filled circle.
+///
+/// Pixels that are entirely inside of the circle are drawn as solid spans,
+/// while pixels along the edge are blended into the image based on how much
+/// of the pixel the circle covers.  The center and radius may be
+/// floating-point numbers.
+///
+/// ```
+/// use freehand::conics::antialiased_circle_filled;
+/// # use image::{RgbaImage, Rgba};
+/// # let mut image = RgbaImage::new(400, 400);
+///
+/// antialiased_circle_filled(&mut image, 5.5, (200.5, 200.5), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+///
+/// See also: [`Draw::antialiased_circle_filled`](crate::Draw::antialiased_circle_filled)
+///
+pub fn antialiased_circle_filled<C, T>(
+    image: &mut image::RgbaImage,
+    radius: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let (r, c) = radius_center(radius, center);
+    let (width, height) = (image.width() as i64, image.height() as i64);
+
+    // Pixels further than half a pixel outside of the circle are not covered
+    let ro = r + 0.5;
+    let ri = r - 0.5;
+
+    let y1 = ((c.y() - ro).ceil() as i64).max(0);
+    let y2 = ((c.y() + ro).floor() as i64).min(height - 1);
+
+    for y in y1..=y2 {
+        let dy = y as f64 - c.y();
+        let dx = (ro * ro - dy * dy).max(0.0).sqrt();
+
+        let x1 = ((c.x() - dx).ceil() as i64).max(0);
+        let x2 = ((c.x() + dx).floor() as i64).min(width - 1);
+
+        // Pixels that are at least half a pixel inside of the circle are fully covered
+        let (s1, s2) = if ri > dy.abs() {
+            let dx = (ri * ri - dy * dy).sqrt();
+            ((c.x() - dx).ceil() as i64, (c.x() + dx).floor() as i64)
+        } else {
+            (x2 + 1, x2)
+        };
+
+        for x in x1..=x2 {
+            let opacity = if (s1..=s2).contains(&x) {
+                1.0
+            } else {
+                let dist = (x as f64 - c.x()).hypot(dy);
+                (ro - dist).clamp(0.0, 1.0)
+            };
+
+            if opacity > 0.0 {
+                // Safe because the coordinates have been clamped to the image bounds
+                unsafe {
+                    crate::ops::blend_at_unchecked(
+                        image,
+                        x as u32,
+                        y as u32,
+                        opacity as f32,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Converts the radius and center into floating-point numbers.
+///
+/// # Panics
+///
+/// Panics if `radius` is negative.
+fn radius_center<C, T>(radius: T, center: C) -> (f64, Pt<f64>)
+where
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    let r = radius.into();
+    assert!(r >= 0.0, "Radius must be non-negative.  radius={r:.1}");
+
+    (r, Pt::new(center.x().into(), center.y().into()))
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn circle() -> Result<(), image::ImageError> {
         crate::logger(crate::LOG_LEVEL);
@@ -61,4 +290,149 @@ mod tests {
 
         image.save("images/circle.png")
     }
+
+    #[test]
+    fn circle_sub_pixel() {
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        for (r, c) in [
+            (5.5, (15.5, 15.5)),
+            (9.0, (15.5, 15.0)),
+            (10.25, (15.25, 14.75)),
+            (12.7, (15.0, 15.0)),
+        ] {
+            let mut image = crate::test::img::blank((31, 31));
+            super::circle(&mut image, r, c, color);
+
+            let pixels: Vec<(i64, i64)> = image
+                .enumerate_pixels()
+                .filter(|(_, _, &p)| p == color)
+                .map(|(x, y, _)| (i64::from(x), i64::from(y)))
+                .collect();
+
+            for &(x, y) in &pixels {
+                let d = (x as f64 - c.0).hypot(y as f64 - c.1);
+                assert!((d - r).abs() < 0.75, "({x}, {y}) is {d} from the center");
+
+                let neighbors = pixels
+                    .iter()
+                    .filter(|&&(nx, ny)| {
+                        (nx, ny) != (x, y) && (nx - x).abs() <= 1 && (ny - y).abs() <= 1
+                    })
+                    .count();
+                assert!(neighbors >= 2, "gap next to ({x}, {y}) for r={r} c={c:?}");
+            }
+
+            // Circles centered between pixels are mirrored around the center
+            if c == (15.5, 15.5) {
+                for &(x, y) in &pixels {
+                    assert!(pixels.contains(&(31 - x, y)));
+                    assert!(pixels.contains(&(x, 31 - y)));
+                    assert!(pixels.contains(&(y, x)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn circle_alpha_blends_once() {
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        for (r, c) in [
+            (7.0, (20.0, 20.0)),
+            (15.0, (20.0, 20.0)),
+            (12.5, (20.5, 19.5)),
+        ] {
+            let mut solid = crate::test::img::blank((41, 41));
+            let mut blended = crate::test::img::blank((41, 41));
+            super::circle(&mut solid, r, c, color);
+            super::circle_alpha(&mut blended, r, c, 0.5, color);
+
+            crate::test::assert_blended_once(&solid, &blended);
+        }
+
+        // Partially outside of the image
+        let mut solid = crate::test::img::blank((41, 41));
+        let mut blended = crate::test::img::blank((41, 41));
+        super::circle(&mut solid, 30, (5, 35), color);
+        super::circle_alpha(&mut blended, 30, (5, 35), 0.5, color);
+        crate::test::assert_blended_once(&solid, &blended);
+    }
+
+    #[test]
+    fn filled_circle() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let mut image = crate::circle_guides(crate::RADIUS);
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        super::circle_filled(&mut image, crate::RADIUS_F, (200.0, 200.0), color);
+
+        image.save("images/circle_filled.png")
+    }
+
+    #[test]
+    fn filled_circle_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let mut image = crate::test::img::blank((crate::IMG_SIZE, crate::IMG_SIZE));
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        super::antialiased_circle_filled(&mut image, crate::RADIUS_F, (200.0, 200.0), color);
+        for (i, r) in [1.5, 2.5, 3.75, 5.0, 8.25].into_iter().enumerate() {
+            let x = 10.0 + 20.0 * i as f64 + 0.3 * i as f64;
+            super::antialiased_circle_filled(&mut image, r, (x, 10.5), color);
+        }
+
+        image.save("images/circle_filled_aa.png")
+    }
+
+    #[test]
+    fn circle_filled_aa_coverage() {
+        let mut image = crate::test::img::blank((5, 5));
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        super::antialiased_circle_filled(&mut image, 1.0, (2.0, 2.0), color);
+
+        assert_eq!(image.get_pixel(2, 2), &color);
+        for (x, y) in [(2, 1), (1, 2), (3, 2), (2, 3)] {
+            assert_eq!(image.get_pixel(x, y), &image::Rgba([255, 127, 127, 255]));
+        }
+        assert_eq!(image.get_pixel(0, 0), &image::Rgba([255, 255, 255, 255]));
+    }
+
+    mod circle_filled {
+        test_pixels_changed!(
+            circle_filled_center,
+            circle_filled(1.0, (2.0, 2.0)),
+            5,
+            &*vec![(2, 1), (1, 2), (2, 2), (3, 2), (2, 3)]
+        );
+
+        test_pixels_changed!(
+            circle_filled_sub_pixel,
+            circle_filled(0.75, (1.5, 1.5)),
+            5,
+            &*vec![(1, 1), (2, 1), (1, 2), (2, 2)]
+        );
+
+        test_pixels_changed!(
+            circle_filled_bounds,
+            circle_filled(3.0, (0.0, 0.0)),
+            5,
+            &*vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (3, 0),
+                (0, 1),
+                (1, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+                (0, 3),
+            ]
+        );
+    }
 }
@@ -0,0 +1,58 @@
+use crate::{angle, Pt};
+
+/// Splits a circular arc into dashes of equal length, measured along the arc
+/// from its start angle.
+///
+/// Points are tested by their angle around the center, so the order they are
+/// visited in does not matter.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct ArcDashes {
+    /// Start angle in radians
+    start: f64,
+    /// Angle covered by the arc in radians
+    sweep: f64,
+    /// Radius
+    r: f64,
+    /// Center
+    c: Pt<f64>,
+    /// Length of a dash and of the gap after it
+    dash: f64,
+}
+
+impl ArcDashes {
+    /// Creates dashes for an arc going counter-clockwise from `start` to `end`
+    /// (in radians).  Equal angles cover the full circle.
+    pub(super) fn new(start: f64, end: f64, r: f64, c: Pt<f64>, dash_width: u16) -> Self {
+        let start = angle::normalize(start);
+        let sweep = angle::normalize(end - start);
+
+        Self {
+            start,
+            sweep: if sweep <= crate::TINY {
+                crate::PI2
+            } else {
+                sweep
+            },
+            r,
+            c,
+            dash: f64::from(dash_width),
+        }
+    }
+
+    /// Checks whether a point falls on a dash rather than a gap.
+    pub(super) fn on(&self, pt: Pt<f64>) -> bool {
+        let a = (self.c.y() - pt.y()).atan2(pt.x() - self.c.x());
+        let mut d = angle::normalize(a - self.start);
+
+        if d > self.sweep {
+            // Rounding to a pixel can place a point just past either end
+            d = if d - self.sweep < crate::PI2 - d {
+                self.sweep
+            } else {
+                0.0
+            };
+        }
+
+        (d * self.r / self.dash) as u64 % 2 == 0
+    }
+}
@@ -0,0 +1,439 @@
+mod aa;
+mod arc;
+mod filled;
+mod pos;
+mod range;
+mod rotated;
+
+use crate::Pt;
+use pos::Pos;
+use range::AngleRange;
+
+pub use aa::{
+    antialiased_ellipse, antialiased_ellipse_arc, antialiased_rotated_ellipse, AntialiasedEllipse,
+    AntialiasedEllipseArc,
+};
+pub use arc::{ellipse_arc, EllipseArc};
+pub use filled::{ellipse_filled, ellipse_pie_slice_filled};
+
+/// Draws a full axis-aligned ellipse.
+///
+/// # Examples
+///
+/// Draws an ellipse that is wider than it is tall:
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::ellipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let x_radius = 190;
+/// let y_radius = 120;
+/// let center = (200, 200);
+/// ellipse(&mut image, x_radius, y_radius, center, color);
+/// ```
+///
+/// Uses [`Ellipse`] to calculate a single quadrant and draw those pixels
+/// in all quadrants.
+///
+/// See also: [`Draw::ellipse`](crate::Draw::ellipse)
+///
+pub fn ellipse<C, I, T>(image: &mut I, x_radius: T, y_radius: T, center: C, color: I::Pixel)
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    Ellipse::new(x_radius, y_radius, center).draw(image, color);
+}
+
+/// Draws an ellipse that has been rotated counter-clockwise around its center.
+///
+/// A floating-point rotation will represent an angle in radians.  Integer
+/// types will represent an angle in degrees.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::rotated_ellipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // The horizontal radius is tilted up by 30°
+/// rotated_ellipse(&mut image, 190, 120, 30, (200, 200), color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either radius is less than or equal to 0
+///
+/// See also: [`Draw::rotated_ellipse`](crate::Draw::rotated_ellipse)
+///
+pub fn rotated_ellipse<A, C, I, T>(
+    image: &mut I,
+    x_radius: T,
+    y_radius: T,
+    rotation: A,
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    Ellipse::rotated(x_radius, y_radius, rotation, center).draw(image, color);
+}
+
+/// A structure for iterating over points in an ellipse.
+///
+/// Implements [`Iterator`], however points are not returned in order around
+/// the ellipse: each point in the first quadrant is returned along with its
+/// reflections in the other three quadrants.  Points that lie on an axis are
+/// only returned once.
+///
+/// Rotated ellipses are created with [`Ellipse::rotated`] and return their
+/// points column by column, then row by row.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::Ellipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// /// Create the struct
+/// let ellipse = Ellipse::new(190, 120, (200, 200));
+///
+/// /// Draw the struct
+/// ellipse.draw(&mut image, color);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Ellipse {
+    /// Current iteration position within a single quadrant.
+    pos: Pos,
+    /// Current point in local coordinates.
+    pt: Pt<i32>,
+    /// The next quadrant to reflect `pt` into.  Values larger than 4 mean the
+    /// next point needs to be calculated.
+    quad: u8,
+    /// Center of the ellipse
+    c: Pt<i32>,
+    /// Horizontal radius
+    xr: i32,
+    /// Vertical radius
+    yr: i32,
+    /// Rotation in radians
+    rotation: f64,
+    /// Iteration position for rotated ellipses
+    rotated: Option<rotated::Pos>,
+}
+
+impl Ellipse {
+    /// Creates a new [`Ellipse`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0
+    ///
+    /// ```
+    /// # use freehand::conics::Ellipse;
+    /// let ellipse = Ellipse::new(190, 120, (200, 200));
+    /// ```
+    pub fn new<T, C>(x_radius: T, y_radius: T, center: C) -> Self
+    where
+        T: Into<i32> + Copy,
+        C: crate::pt::Point<T>,
+    {
+        let c = Pt::new(center.x().into(), center.y().into());
+        let (xr, yr) = radii(x_radius, y_radius);
+
+        Self {
+            pos: Pos::new(xr, yr),
+            pt: Pt::new(0, 0),
+            quad: 5,
+            c,
+            xr,
+            yr,
+            rotation: 0.0,
+            rotated: None,
+        }
+    }
+
+    /// Creates a new [`Ellipse`] that is rotated counter-clockwise around its center.
+    ///
+    /// A floating-point rotation will represent an angle in radians.  Integer
+    /// types will represent an angle in degrees.  A rotation of 0 draws the
+    /// same pixels as [`Ellipse::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0
+    ///
+    /// ```
+    /// # use freehand::conics::Ellipse;
+    /// let ellipse = Ellipse::rotated(190, 120, 30, (200, 200));
+    /// ```
+    pub fn rotated<A, T, C>(x_radius: T, y_radius: T, rotation: A, center: C) -> Self
+    where
+        A: crate::Angle,
+        T: Into<i32> + Copy,
+        C: crate::pt::Point<T>,
+    {
+        let mut ellipse = Self::new(x_radius, y_radius, center);
+        ellipse.rotation = crate::angle::normalize(rotation.radians());
+
+        if !is_aligned(ellipse.rotation) {
+            ellipse.rotated = Some(rotated::Pos::new(
+                ellipse.xr as f64,
+                ellipse.yr as f64,
+                ellipse.rotation,
+                ellipse.c.into(),
+            ));
+        }
+        ellipse
+    }
+
+    /// Draw the ellipse by iterating over its points.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::Ellipse;
+    /// # let bg = Rgba([255, 255, 255, 255]); // white
+    /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+    /// let ellipse = Ellipse::new(190, 120, (200, 200));
+    /// ellipse.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
+    where
+        I: image::GenericImage,
+    {
+        for pt in self {
+            let pt: Result<Pt<u32>, &'static str> = pt.try_into();
+            if let Ok(pt) = pt {
+                if pt.x() < image.width() && pt.y() < image.height() {
+                    image.put_pixel(pt.x(), pt.y(), color);
+                }
+            }
+        }
+    }
+
+    /// Returns the center coordinates
+    #[must_use]
+    pub fn center(&self) -> Pt<i32> {
+        self.c
+    }
+
+    /// Returns the horizontal radius
+    #[must_use]
+    pub fn x_radius(&self) -> i32 {
+        self.xr
+    }
+
+    /// Returns the vertical radius
+    #[must_use]
+    pub fn y_radius(&self) -> i32 {
+        self.yr
+    }
+
+    /// Returns the counter-clockwise rotation in radians
+    #[must_use]
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+}
+
+impl Iterator for Ellipse {
+    type Item = Pt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(rotated) = &mut self.rotated {
+            return rotated.next();
+        }
+
+        loop {
+            if self.quad > 4 {
+                self.pt = self.pos.step()?;
+                self.quad = 1;
+            }
+
+            let quad = self.quad;
+            self.quad += 1;
+
+            // Points on an axis would be returned twice
+            if (self.pt.x() == 0 && (quad == 2 || quad == 3)) || (self.pt.y() == 0 && quad > 2) {
+                continue;
+            }
+
+            return Some(quad_to_real(self.pt, quad, self.c));
+        }
+    }
+}
+
+/// Converts the radii into `i32`s.
+///
+/// # Panics
+///
+/// Panics if either radius is less than or equal to 0
+fn radii<T: Into<i32>>(x_radius: T, y_radius: T) -> (i32, i32) {
+    let xr = x_radius.into();
+    let yr = y_radius.into();
+
+    assert!(
+        xr > 0 && yr > 0,
+        "Radii must be larger than 0.  x_radius={xr} y_radius={yr}"
+    );
+
+    (xr, yr)
+}
+
+/// Checks whether a normalized rotation leaves the ellipse axis-aligned.
+fn is_aligned(rotation: f64) -> bool {
+    !(crate::TINY..=crate::PI2 - crate::TINY).contains(&rotation)
+}
+
+/// Reflect a point from local coordinates into the specified quadrant and translate it to the center.
+fn quad_to_real<T>(pt: Pt<T>, quad: u8, c: Pt<T>) -> Pt<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+{
+    match quad {
+        1 => Pt::new(c.x() + pt.x(), c.y() - pt.y()),
+        2 => Pt::new(c.x() - pt.x(), c.y() - pt.y()),
+        3 => Pt::new(c.x() - pt.x(), c.y() + pt.y()),
+        _ => Pt::new(c.x() + pt.x(), c.y() + pt.y()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ellipse() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let xr = 190;
+        let yr = 120;
+        let c = (200, 200);
+
+        let mut image = crate::test::img::setup_ellipse(xr, yr, c);
+
+        super::ellipse(&mut image, xr, yr, c, image::Rgba([255, 0, 0, 255]));
+
+        image.save("images/ellipse.png")
+    }
+
+    #[test]
+    fn ellipse_no_gaps() {
+        for (xr, yr) in [
+            (1, 1),
+            (1, 9),
+            (9, 1),
+            (2, 30),
+            (30, 2),
+            (57, 23),
+            (190, 120),
+        ] {
+            let mut pos = Pos::new(xr, yr);
+            let mut last = pos.step().unwrap();
+            assert_eq!(last, Pt::new(xr, 0));
+
+            while let Some(pt) = pos.step() {
+                assert!(
+                    (pt.x() - last.x()).abs() <= 1 && (pt.y() - last.y()).abs() <= 1 && pt != last,
+                    "Gap found between {last} and {pt}.  x_radius={xr} y_radius={yr}"
+                );
+                last = pt;
+            }
+            assert_eq!(last, Pt::new(0, yr));
+
+            let pts: Vec<Pt<i32>> = Ellipse::new(xr, yr, (0, 0)).collect();
+            for (i, a) in pts.iter().enumerate() {
+                assert!(
+                    !pts[i + 1..].contains(a),
+                    "Duplicate point {a} found.  x_radius={xr} y_radius={yr}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_ellipse() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        super::rotated_ellipse(&mut image, 190, 80, 30, (200, 200), color);
+        super::rotated_ellipse(&mut image, 150, 40, -70, (200, 200), color);
+
+        image.save("images/rotated_ellipse.png")
+    }
+
+    #[test]
+    fn rotated_ellipse_aligned() {
+        let pts: Vec<Pt<i32>> = Ellipse::new(57, 23, (100, 100)).collect();
+        assert_eq!(
+            pts,
+            Ellipse::rotated(57, 23, 0, (100, 100)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            pts,
+            Ellipse::rotated(57, 23, 360, (100, 100)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rotated_ellipse_no_gaps() {
+        for (xr, yr, rotation) in [
+            (1, 1, 45),
+            (9, 2, 10),
+            (30, 3, 80),
+            (57, 23, 30),
+            (57, 23, 135),
+            (190, 120, 200),
+            (190, 20, 333),
+        ] {
+            let mut pts: Vec<Pt<i32>> = Ellipse::rotated(xr, yr, rotation, (0, 0)).collect();
+            let len = pts.len();
+            pts.sort();
+            pts.dedup();
+            assert_eq!(
+                len,
+                pts.len(),
+                "Duplicate points found.  x_radius={xr} y_radius={yr} rotation={rotation}"
+            );
+
+            // Every point should be reachable from the first point
+            let mut found = vec![pts[0]];
+            let mut i = 0;
+            while i < found.len() {
+                let a = found[i];
+                for &b in &pts {
+                    if (a.x() - b.x()).abs() <= 1
+                        && (a.y() - b.y()).abs() <= 1
+                        && !found.contains(&b)
+                    {
+                        found.push(b);
+                    }
+                }
+                i += 1;
+            }
+            assert_eq!(
+                found.len(),
+                pts.len(),
+                "Gap found.  x_radius={xr} y_radius={yr} rotation={rotation}"
+            );
+        }
+    }
+}
@@ -0,0 +1,565 @@
+mod pos;
+mod rotated;
+
+use super::{is_aligned, quad_to_real, AngleRange};
+use crate::antialias::AAPt;
+use crate::{angle, Pt};
+use pos::Pos;
+
+/// Draws an antialiased axis-aligned ellipse.
+///
+/// See also: [`Draw::antialiased_ellipse`](crate::Draw::antialiased_ellipse)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_ellipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let x_radius = 190;
+/// let y_radius = 120;
+/// let center = (200, 200);
+///
+/// antialiased_ellipse(&mut image, x_radius, y_radius, center, color);
+/// ```
+pub fn antialiased_ellipse<C, T>(
+    image: &mut image::RgbaImage,
+    x_radius: T,
+    y_radius: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    AntialiasedEllipse::new(x_radius, y_radius, center).draw(image, color);
+}
+
+/// Draws an antialiased ellipse that has been rotated counter-clockwise around its center.
+///
+/// A floating-point rotation will represent an angle in radians.  Integer
+/// types will represent an angle in degrees.
+///
+/// See also: [`Draw::antialiased_rotated_ellipse`](crate::Draw::antialiased_rotated_ellipse)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_rotated_ellipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // The horizontal radius is tilted up by 30°
+/// antialiased_rotated_ellipse(&mut image, 190.0, 120.0, 30, (200.0, 200.0), color);
+/// ```
+pub fn antialiased_rotated_ellipse<A, C, T>(
+    image: &mut image::RgbaImage,
+    x_radius: T,
+    y_radius: T,
+    rotation: A,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    AntialiasedEllipse::rotated(x_radius, y_radius, rotation, center).draw(image, color);
+}
+
+/// Draws an antialiased elliptical arc.
+///
+/// If the angles are floating-point numbers they are interpreted as radians.
+/// Otherwise the angles are interpreted as degrees.
+///
+/// Angles are measured from the center of the ellipse, the same as with
+/// [`ellipse_arc`](crate::conics::ellipse_arc).
+///
+/// See also: [`Draw::antialiased_ellipse_arc`](crate::Draw::antialiased_ellipse_arc)
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::antialiased_ellipse_arc;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let x_radius = 190;
+/// let y_radius = 120;
+/// let center = (200, 200);
+/// let start = 0; // 0°
+/// let end = 180; // 180°
+///
+/// antialiased_ellipse_arc(&mut image, start, end, x_radius, y_radius, center, color);
+/// ```
+pub fn antialiased_ellipse_arc<A, C, T>(
+    image: &mut image::RgbaImage,
+    start_angle: A,
+    end_angle: A,
+    x_radius: T,
+    y_radius: T,
+    center: C,
+    color: image::Rgba<u8>,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    T: Into<f64> + Copy,
+{
+    AntialiasedEllipseArc::new(start_angle, end_angle, x_radius, y_radius, center)
+        .draw(image, color);
+}
+
+/// An antialiased ellipse.  Implements [`Iterator`].
+///
+/// Points are not returned in order around the ellipse: each point in the
+/// first quadrant is returned along with its reflections in the other three
+/// quadrants.  Rotated ellipses, created with [`AntialiasedEllipse::rotated`],
+/// return their points column by column, then row by row.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::AntialiasedEllipse;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let ellipse = AntialiasedEllipse::new(190, 120, (200, 200));
+///
+/// ellipse.draw(&mut image, color);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct AntialiasedEllipse {
+    /// Current iteration position within a single quadrant
+    pos: Pos,
+    /// Current point in local coordinates
+    pt: AAPt<f64>,
+    /// The next quadrant to reflect `pt` into.  Values larger than 4 mean the
+    /// next point needs to be calculated.
+    quad: u8,
+    /// Center coordinates
+    c: Pt<f64>,
+    /// Iteration position for rotated ellipses
+    rotated: Option<rotated::Pos>,
+}
+
+impl AntialiasedEllipse {
+    /// Creates a new [`AntialiasedEllipse`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedEllipse;
+    /// let ellipse = AntialiasedEllipse::new(190, 120, (200, 200));
+    /// ```
+    pub fn new<P, T>(x_radius: T, y_radius: T, center: P) -> Self
+    where
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (xr, yr) = radii(x_radius, y_radius);
+
+        Self {
+            pos: Pos::new(xr, yr),
+            pt: AAPt::new(Pt::new(0.0, 0.0), Pt::new(0.0, 0.0), 0.0),
+            quad: 5,
+            c: Pt::new(center.x().into(), center.y().into()),
+            rotated: None,
+        }
+    }
+
+    /// Creates a new [`AntialiasedEllipse`] that is rotated counter-clockwise
+    /// around its center.
+    ///
+    /// A floating-point rotation will represent an angle in radians.  Integer
+    /// types will represent an angle in degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedEllipse;
+    /// let ellipse = AntialiasedEllipse::rotated(190, 120, 30, (200, 200));
+    /// ```
+    pub fn rotated<A, P, T>(x_radius: T, y_radius: T, rotation: A, center: P) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let mut ellipse = Self::new(x_radius, y_radius, center);
+        let rotation = angle::normalize(rotation.radians());
+
+        if !is_aligned(rotation) {
+            let (xr, yr) = radii(x_radius, y_radius);
+            ellipse.rotated = Some(rotated::Pos::new(xr, yr, rotation, ellipse.c));
+        }
+        ellipse
+    }
+
+    /// Draw an antialiased ellipse by iterating over all of its pixels
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::AntialiasedEllipse;
+    /// # let bg = Rgba([255, 255, 255, 255]); // white
+    /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+    /// let ellipse = AntialiasedEllipse::new(190, 120, (200, 200));
+    /// ellipse.draw(&mut image, Rgba([255, 0, 0, 255]))
+    /// ```
+    pub fn draw(self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        for pt in self {
+            pt.draw(image, color);
+        }
+    }
+}
+
+impl Iterator for AntialiasedEllipse {
+    type Item = AAPt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(rotated) = &mut self.rotated {
+            return rotated.next().map(|pt| image_pt(&pt));
+        }
+
+        loop {
+            if self.quad > 4 {
+                self.pt = self.pos.step()?;
+                self.quad = 1;
+            }
+
+            let quad = self.quad;
+            self.quad += 1;
+
+            if on_other_axis(&self.pt, quad) {
+                continue;
+            }
+
+            return Some(to_real(&self.pt, quad, self.c));
+        }
+    }
+}
+
+/// An antialiased elliptical arc.  Implements [`Iterator`].
+///
+/// Points are only returned in order from the start angle in even quadrants
+/// (90°-180° and 270°-360°).  In odd quadrants points are returned in reverse
+/// order.  Rotated arcs, created with [`AntialiasedEllipseArc::rotated`],
+/// return their points column by column, then row by row.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::AntialiasedEllipseArc;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let arc = AntialiasedEllipseArc::new(0, 180, 190, 120, (200, 200));
+///
+/// arc.draw(&mut image, color);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct AntialiasedEllipseArc {
+    /// Current iteration position within the current quadrant
+    pos: Pos,
+    /// Current quadrant
+    quad: u8,
+    /// Number of quadrants left to visit after the current quadrant
+    remaining: u8,
+    /// Start and end angles
+    range: AngleRange,
+    /// Center coordinates
+    c: Pt<f64>,
+    /// Horizontal radius
+    xr: f64,
+    /// Vertical radius
+    yr: f64,
+    /// Iteration position for rotated ellipses
+    rotated: Option<rotated::Pos>,
+}
+
+impl AntialiasedEllipseArc {
+    /// Creates a new [`AntialiasedEllipseArc`].
+    ///
+    /// If the angles are floating-point numbers they are interpreted as radians.
+    /// Otherwise the angles are interpreted as degrees.
+    ///
+    /// Negative angles are supported as well as angles larger than 360° (or
+    /// larger than`2*PI` for radians).  Angles will be normalized into a range
+    /// of 0..PI*2.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedEllipseArc;
+    /// let arc = AntialiasedEllipseArc::new(0, 180, 190, 120, (200, 200));
+    /// ```
+    pub fn new<A, P, T>(start: A, end: A, x_radius: T, y_radius: T, center: P) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (xr, yr) = radii(x_radius, y_radius);
+
+        let range = AngleRange::new(start, end);
+        let quad = angle::angle_to_quad(range.start);
+        let end_quad = angle::angle_to_quad(range.end);
+
+        let remaining = match (end_quad + 4 - quad) % 4 {
+            0 if range.start > range.end => 3,
+            n => n,
+        };
+
+        Self {
+            pos: Pos::new(xr, yr),
+            quad,
+            remaining,
+            range,
+            c: Pt::new(center.x().into(), center.y().into()),
+            xr,
+            yr,
+            rotated: None,
+        }
+    }
+
+    /// Creates a new [`AntialiasedEllipseArc`] on an ellipse that is rotated
+    /// counter-clockwise around its center.
+    ///
+    /// If the angles are floating-point numbers they are interpreted as radians.
+    /// Otherwise the angles are interpreted as degrees.
+    ///
+    /// The start and end angles are measured from the rotated horizontal
+    /// radius, so the arc rotates along with the ellipse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0.
+    ///
+    /// ```
+    /// # use freehand::conics::AntialiasedEllipseArc;
+    /// // The top half of an ellipse, tilted by 30°
+    /// let arc = AntialiasedEllipseArc::rotated(0, 180, 190, 120, 30, (200, 200));
+    /// ```
+    pub fn rotated<A, P, T>(
+        start: A,
+        end: A,
+        x_radius: T,
+        y_radius: T,
+        rotation: A,
+        center: P,
+    ) -> Self
+    where
+        A: crate::Angle,
+        P: crate::Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let rotation = angle::normalize(rotation.radians());
+        let mut arc = Self::new(
+            start.radians() + rotation,
+            end.radians() + rotation,
+            x_radius,
+            y_radius,
+            center,
+        );
+
+        if !is_aligned(rotation) {
+            arc.rotated = Some(rotated::Pos::new(arc.xr, arc.yr, rotation, arc.c));
+        }
+        arc
+    }
+
+    /// Draw an antialiased elliptical arc by iterating over all of its pixels
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::AntialiasedEllipseArc;
+    /// # let bg = Rgba([255, 255, 255, 255]); // white
+    /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+    /// let arc = AntialiasedEllipseArc::new(0, 180, 190, 120, (200, 200));
+    /// arc.draw(&mut image, Rgba([255, 0, 0, 255]))
+    /// ```
+    pub fn draw(self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        for pt in self {
+            pt.draw(image, color);
+        }
+    }
+
+    /// Move to the beginning of the next quadrant.  Returns false if there are
+    /// no quadrants left.
+    fn next_quad(&mut self) -> bool {
+        if self.remaining == 0 {
+            return false;
+        }
+        self.remaining -= 1;
+        self.quad = self.quad % 4 + 1;
+        self.pos = Pos::new(self.xr, self.yr);
+        true
+    }
+}
+
+impl Iterator for AntialiasedEllipseArc {
+    type Item = AAPt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(rotated) = &mut self.rotated {
+            let (range, c) = (self.range, self.c);
+            return rotated
+                .find(|pt| range.contains(pos::exact(pt), c))
+                .map(|pt| image_pt(&pt));
+        }
+
+        loop {
+            let pt = match self.pos.step() {
+                Some(pt) => pt,
+                None if self.next_quad() => continue,
+                None => return None,
+            };
+
+            if on_other_axis(&pt, self.quad) {
+                continue;
+            }
+
+            let exact = quad_to_real(pos::exact(&pt), self.quad, self.c);
+            if self.range.contains(exact, self.c) {
+                return Some(to_real(&pt, self.quad, self.c));
+            }
+        }
+    }
+}
+
+/// Converts the radii into `f64`s.
+///
+/// # Panics
+///
+/// Panics if either radius is less than or equal to 0
+fn radii<T: Into<f64>>(x_radius: T, y_radius: T) -> (f64, f64) {
+    let xr = x_radius.into();
+    let yr = y_radius.into();
+
+    assert!(
+        xr > 0.0 && yr > 0.0,
+        "Radii must be larger than 0.  x_radius={xr:.1} y_radius={yr:.1}"
+    );
+
+    (xr, yr)
+}
+
+/// Checks whether a point lies on an axis that belongs to a neighboring quadrant.
+///
+/// Points on an axis belong to the quadrant that starts at that axis.
+fn on_other_axis(pt: &AAPt<f64>, quad: u8) -> bool {
+    if quad % 2 == 1 {
+        pt.a.x() == 0.0 && pt.b.x() == 0.0
+    } else {
+        pt.a.y() == 0.0 && pt.b.y() == 0.0
+    }
+}
+
+/// Reflect an antialiased point into the specified quadrant and convert it to image coordinates.
+fn to_real(pt: &AAPt<f64>, quad: u8, c: Pt<f64>) -> AAPt<i32> {
+    AAPt::new(
+        quad_to_real(pt.a, quad, c).i32(),
+        quad_to_real(pt.b, quad, c).i32(),
+        pt.ob,
+    )
+}
+
+/// Converts an antialiased point that is already in image coordinates.
+fn image_pt(pt: &AAPt<f64>) -> AAPt<i32> {
+    AAPt::new(pt.a.i32(), pt.b.i32(), pt.ob)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RADS;
+
+    #[test]
+    fn ellipse_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        AntialiasedEllipse::new(190.0, 120.0, (200.0, 200.0)).draw(&mut image, color);
+        AntialiasedEllipse::new(40.0, 150.0, (200.0, 200.0)).draw(&mut image, color);
+
+        image.save("images/ellipse_aa.png")
+    }
+
+    #[test]
+    fn ellipse_arc_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let start = RADS * 0.8;
+        let end = RADS * 7.4;
+        let color = image::Rgba([255, 0, 0, 255]);
+
+        AntialiasedEllipseArc::new(start, end, 190.0, 120.0, (200.0, 200.0))
+            .draw(&mut image, color);
+
+        image.save("images/ellipse_arc_aa.png")
+    }
+
+    #[test]
+    fn ellipse_aa_no_duplicates() {
+        // Very flat ellipses are excluded: near their tips both halves of the
+        // ellipse pass through the same pixels, which should be blended twice
+        for (xr, yr) in [(1.0, 1.0), (23.0, 57.0), (57.0, 23.0), (190.0, 120.0)] {
+            let mut pts: Vec<Pt<i32>> = AntialiasedEllipse::new(xr, yr, (0.0, 0.0))
+                .flat_map(|pt| [pt.a, pt.b])
+                .collect();
+            let len = pts.len();
+            pts.sort();
+            pts.dedup();
+            assert_eq!(len, pts.len(), "x_radius={xr} y_radius={yr}");
+        }
+    }
+
+    #[test]
+    fn ellipse_arc_aa_order() {
+        // Counter-clockwise angle of a point around the center
+        let angle = |pt: Pt<i32>| {
+            crate::angle::normalize(f64::from(100 - pt.y()).atan2(f64::from(pt.x() - 100)))
+        };
+
+        // In order from the start angle in 90°-180° and 270°-360°, reversed otherwise
+        for (start, end, in_order) in [
+            (10, 80, false),
+            (100, 170, true),
+            (190, 260, false),
+            (280, 350, true),
+        ] {
+            let pts: Vec<AAPt<i32>> =
+                AntialiasedEllipseArc::new(start, end, 57.0, 23.0, (100.0, 100.0)).collect();
+            let (first, last) = (angle(pts[0].a), angle(pts[pts.len() - 1].a));
+            assert_eq!(first < last, in_order, "{start}-{end}");
+        }
+    }
+
+    #[test]
+    fn rotated_ellipse_aa() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        let color = image::Rgba([255, 0, 0, 255]);
+        AntialiasedEllipse::rotated(190.0, 80.0, 30, (200.0, 200.0)).draw(&mut image, color);
+        AntialiasedEllipseArc::rotated(0, 250, 150.0, 40.0, -70, (200.5, 200.5))
+            .draw(&mut image, color);
+
+        image.save("images/rotated_ellipse_aa.png")
+    }
+}
@@ -0,0 +1,89 @@
+use crate::antialias::AAPt;
+use crate::Pt;
+
+/// Steps through a single quadrant of an antialiased ellipse.
+///
+/// Points are returned in local coordinates (both positive).  The x coordinate
+/// is incremented until the slope of the ellipse reaches -1, then the y
+/// coordinate is decremented down to 0.  The two halves are split so that no
+/// pixel is returned twice.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub(super) struct Pos {
+    /// Current local x coordinate
+    x: f64,
+    /// Current local y coordinate
+    y: f64,
+    /// Horizontal radius
+    a: f64,
+    /// Vertical radius
+    b: f64,
+    /// Last x coordinate to increment over before switching to y
+    ex: f64,
+    /// Whether to increment x (true) or decrement y (false)
+    fast_x: bool,
+}
+
+impl Pos {
+    pub(super) fn new(a: f64, b: f64) -> Self {
+        // The point where the slope of the ellipse is -1
+        let ex = (a * a / (a * a + b * b).sqrt()).floor();
+
+        Self {
+            x: 0.0,
+            y: b,
+            a,
+            b,
+            ex,
+            fast_x: true,
+        }
+    }
+
+    /// Returns the next pair of pixels, or `None` once the end of the quadrant has been reached.
+    pub(super) fn step(&mut self) -> Option<AAPt<f64>> {
+        if self.fast_x {
+            if self.x <= self.ex {
+                let x = self.x;
+                let (ya, yb, da) = calc_fract(self.calc_y(x));
+                self.x += 1.0;
+                return Some(AAPt::new(Pt::new(x, ya), Pt::new(x, yb), da));
+            }
+            // Start below the last row crossed by the final column
+            self.fast_x = false;
+            self.y = self.calc_y(self.x).floor();
+        }
+
+        if self.y < 0.0 {
+            return None;
+        }
+
+        let y = self.y;
+        let (xa, xb, da) = calc_fract(self.calc_x(y));
+        self.y -= 1.0;
+        Some(AAPt::new(Pt::new(xa, y), Pt::new(xb, y), da))
+    }
+
+    /// Calculate the y coordinate for a given x coordinate
+    fn calc_y(&self, x: f64) -> f64 {
+        self.b * (1.0 - (x * x) / (self.a * self.a)).max(0.0).sqrt()
+    }
+
+    /// Calculate the x coordinate for a given y coordinate
+    fn calc_x(&self, y: f64) -> f64 {
+        self.a * (1.0 - (y * y) / (self.b * self.b)).max(0.0).sqrt()
+    }
+}
+
+/// Returns the two slow coordinates to antialias and the distance between a and the actual ellipse
+fn calc_fract(slow: f64) -> (f64, f64, f64) {
+    let a = slow.floor();
+    (a, a + 1.0, slow - a)
+}
+
+/// Returns the exact point on the ellipse that an antialiased point was calculated from.
+pub(super) fn exact(pt: &AAPt<f64>) -> Pt<f64> {
+    Pt::new(
+        pt.a.x() + (pt.b.x() - pt.a.x()) * pt.ob,
+        pt.a.y() + (pt.b.y() - pt.a.y()) * pt.ob,
+    )
+}
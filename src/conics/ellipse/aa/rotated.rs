@@ -0,0 +1,94 @@
+use super::super::rotated::Curve;
+use crate::antialias::AAPt;
+use crate::Pt;
+
+/// Iterates over the antialiased pixels of a rotated ellipse.
+///
+/// Columns are visited first, returning pairs of vertically adjacent pixels
+/// where the ellipse is closer to horizontal, then rows are visited for pairs
+/// of horizontally adjacent pixels where it is closer to vertical.
+///
+/// Points are returned in image coordinates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub(super) struct Pos {
+    curve: Curve,
+    /// Next column
+    x: i32,
+    /// Last column
+    x_end: i32,
+    /// Next row
+    y: i32,
+    /// Last row
+    y_end: i32,
+    /// Second point found in the last column or row
+    pending: Option<AAPt<f64>>,
+}
+
+impl Pos {
+    pub(super) fn new(xr: f64, yr: f64, rotation: f64, center: Pt<f64>) -> Self {
+        let curve = Curve::new(xr, yr, rotation, center);
+        let (x, x_end) = curve.columns();
+        let (y, y_end) = curve.rows();
+
+        Self {
+            curve,
+            x,
+            x_end,
+            y,
+            y_end,
+            pending: None,
+        }
+    }
+
+    /// Returns the antialiased points in a column where the ellipse is closer to horizontal.
+    fn column(&self, x: f64) -> [Option<AAPt<f64>>; 2] {
+        self.curve.ys(x).map(|y| {
+            self.curve.flat(x, y).then(|| {
+                let ya = y.floor();
+                AAPt::new(Pt::new(x, ya), Pt::new(x, ya + 1.0), y - ya)
+            })
+        })
+    }
+
+    /// Returns the antialiased points in a row where the ellipse is closer to vertical.
+    fn row(&self, y: f64) -> [Option<AAPt<f64>>; 2] {
+        self.curve.xs(y).map(|x| {
+            self.curve.steep(x, y).then(|| {
+                let xa = x.floor();
+                AAPt::new(Pt::new(xa, y), Pt::new(xa + 1.0, y), x - xa)
+            })
+        })
+    }
+}
+
+impl Iterator for Pos {
+    type Item = AAPt<f64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pt) = self.pending.take() {
+            return Some(pt);
+        }
+
+        loop {
+            let pts = if self.x <= self.x_end {
+                self.x += 1;
+                self.column((self.x - 1) as f64)
+            } else if self.y <= self.y_end {
+                self.y += 1;
+                self.row((self.y - 1) as f64)
+            } else {
+                return None;
+            };
+
+            match pts {
+                [Some(a), b] => {
+                    self.pending = b;
+                    return Some(a);
+                }
+                [None, Some(b)] => return Some(b),
+                [None, None] => {}
+            }
+        }
+    }
+}
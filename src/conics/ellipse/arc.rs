@@ -0,0 +1,417 @@
+use super::{is_aligned, quad_to_real, radii, rotated, AngleRange, Pos};
+use crate::{angle, Pt};
+
+/// Draws an elliptical arc from a given start angle to an end angle.
+///
+/// A floating-point angle will represent an angle in radians.  Integer types
+/// will represent an angle in degrees.
+///
+/// Angles are measured from the center of the ellipse, the same as with
+/// [`arc`](crate::conics::arc).
+///
+/// # Examples
+///
+/// Draws an elliptical arc that goes across the top half of the image (0° to 180°):
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::ellipse_arc;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// let x_radius = 190;
+/// let y_radius = 120;
+/// let center = (200, 200);
+/// let start = 0; // 0°
+/// let end = 180; // 180°
+/// ellipse_arc(&mut image, start, end, x_radius, y_radius, center, color);
+/// ```
+///
+/// Integer numbers for angles are treated as degrees while floating-point numbers
+/// are treated as radians.
+///
+/// This will draw the same image as above using radians (PI = 180°):
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// # use freehand::conics::ellipse_arc;
+/// # let bg = Rgba([255, 255, 255, 255]); // white
+/// # let color = Rgba([255, 0, 0, 255]);
+/// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+/// # let x_radius = 190;
+/// # let y_radius = 120;
+/// # let center = (200, 200);
+/// let start = 0.0;
+/// let end = std::f64::consts::PI;
+/// ellipse_arc(&mut image, start, end, x_radius, y_radius, center, color);
+/// ```
+///
+/// See also: [`Draw::ellipse_arc`](crate::Draw::ellipse_arc)
+///
+pub fn ellipse_arc<A, C, I, T>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    x_radius: T,
+    y_radius: T,
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    EllipseArc::new(start_angle, end_angle, x_radius, y_radius, center).draw(image, color);
+}
+
+/// A structure for iterating over points in an elliptical arc.
+///
+/// Implements [`Iterator`], however points are only returned in order from
+/// the start angle in odd quadrants (0°-90° and 180°-270°).  In even quadrants
+/// points are returned in reverse order.  Rotated arcs, created with
+/// [`EllipseArc::rotated`], return their points column by column, then row by
+/// row.
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::EllipseArc;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// /// An elliptical arc that goes across the top half of the image (0° to 180°)
+/// let arc = EllipseArc::new(0, 180, 190, 120, (200, 200));
+///
+/// /// Draw the struct
+/// arc.draw(&mut image, color);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct EllipseArc {
+    /// Current iteration position within the current quadrant.
+    pos: Pos,
+    /// Current quadrant
+    quad: u8,
+    /// Number of quadrants left to visit after the current quadrant
+    remaining: u8,
+    /// Start and end angles
+    range: AngleRange,
+    /// Center of the ellipse
+    c: Pt<i32>,
+    /// Horizontal radius
+    xr: i32,
+    /// Vertical radius
+    yr: i32,
+    /// Rotation in radians
+    rotation: f64,
+    /// Iteration position for rotated ellipses
+    rotated: Option<rotated::Pos>,
+}
+
+impl EllipseArc {
+    /// Creates a new [`EllipseArc`].
+    ///
+    /// Floating-point angles will represent an angle in radians.  Integer types
+    /// will represent an angle in degrees.
+    ///
+    /// Negative angles are supported as well as angles larger than 360° (or
+    /// larger than`2*PI` for radians).  Angles will be normalized into a range
+    /// of 0..PI*2.  If the start and end angles are the same a full ellipse
+    /// is drawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0
+    ///
+    /// ```
+    /// # use freehand::conics::EllipseArc;
+    /// let arc = EllipseArc::new(0, 180, 190, 120, (200, 200));
+    /// ```
+    pub fn new<A, T, C>(start_angle: A, end_angle: A, x_radius: T, y_radius: T, center: C) -> Self
+    where
+        A: crate::Angle,
+        T: Into<i32> + Copy,
+        C: crate::pt::Point<T>,
+    {
+        let c = Pt::new(center.x().into(), center.y().into());
+        let (xr, yr) = radii(x_radius, y_radius);
+
+        let range = AngleRange::new(start_angle, end_angle);
+        let quad = angle::angle_to_quad(range.start);
+        let end_quad = angle::angle_to_quad(range.end);
+
+        // When both angles are in the same quadrant and the arc wraps all the
+        // way around, every quadrant needs to be visited.
+        let remaining = match (end_quad + 4 - quad) % 4 {
+            0 if range.start > range.end => 3,
+            n => n,
+        };
+
+        Self {
+            pos: Pos::new(xr, yr),
+            quad,
+            remaining,
+            range,
+            c,
+            xr,
+            yr,
+            rotation: 0.0,
+            rotated: None,
+        }
+    }
+
+    /// Creates a new [`EllipseArc`] on an ellipse that is rotated
+    /// counter-clockwise around its center.
+    ///
+    /// Floating-point angles will represent an angle in radians.  Integer types
+    /// will represent an angle in degrees.
+    ///
+    /// The start and end angles are measured from the rotated horizontal
+    /// radius, so the arc rotates along with the ellipse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either radius is less than or equal to 0
+    ///
+    /// ```
+    /// # use freehand::conics::EllipseArc;
+    /// // The top half of an ellipse, tilted by 30°
+    /// let arc = EllipseArc::rotated(0, 180, 190, 120, 30, (200, 200));
+    /// ```
+    pub fn rotated<A, T, C>(
+        start_angle: A,
+        end_angle: A,
+        x_radius: T,
+        y_radius: T,
+        rotation: A,
+        center: C,
+    ) -> Self
+    where
+        A: crate::Angle,
+        T: Into<i32> + Copy,
+        C: crate::pt::Point<T>,
+    {
+        let rotation = angle::normalize(rotation.radians());
+        let mut arc = Self::new(
+            start_angle.radians() + rotation,
+            end_angle.radians() + rotation,
+            x_radius,
+            y_radius,
+            center,
+        );
+        arc.rotation = rotation;
+
+        if !is_aligned(rotation) {
+            arc.rotated = Some(rotated::Pos::new(
+                arc.xr as f64,
+                arc.yr as f64,
+                rotation,
+                arc.c.into(),
+            ));
+        }
+        arc
+    }
+
+    /// Draw the elliptical arc by iterating over its points.
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # use freehand::conics::EllipseArc;
+    /// # let bg = Rgba([255, 255, 255, 255]); // white
+    /// # let mut image = RgbaImage::from_pixel(400, 400, bg);
+    /// let arc = EllipseArc::new(0, 180, 190, 120, (200, 200));
+    /// arc.draw(&mut image, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    pub fn draw<I>(self, image: &mut I, color: I::Pixel)
+    where
+        I: image::GenericImage,
+    {
+        for pt in self {
+            let pt: Result<Pt<u32>, &'static str> = pt.try_into();
+            if let Ok(pt) = pt {
+                if pt.x() < image.width() && pt.y() < image.height() {
+                    image.put_pixel(pt.x(), pt.y(), color);
+                }
+            }
+        }
+    }
+
+    /// Move to the beginning of the next quadrant.  Returns false if there are
+    /// no quadrants left.
+    fn next_quad(&mut self) -> bool {
+        if self.remaining == 0 {
+            return false;
+        }
+        self.remaining -= 1;
+        self.quad = self.quad % 4 + 1;
+        self.pos = Pos::new(self.xr, self.yr);
+        true
+    }
+
+    /// Returns the center coordinates
+    #[must_use]
+    pub fn center(&self) -> Pt<i32> {
+        self.c
+    }
+
+    /// Returns the horizontal radius
+    #[must_use]
+    pub fn x_radius(&self) -> i32 {
+        self.xr
+    }
+
+    /// Returns the vertical radius
+    #[must_use]
+    pub fn y_radius(&self) -> i32 {
+        self.yr
+    }
+
+    /// Returns the counter-clockwise rotation in radians
+    #[must_use]
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+}
+
+impl Iterator for EllipseArc {
+    type Item = Pt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(rotated) = &mut self.rotated {
+            let (range, c) = (self.range, self.c.into());
+            return rotated.find(|&pt| range.contains(pt.into(), c));
+        }
+
+        loop {
+            let pt = match self.pos.step() {
+                Some(pt) => pt,
+                None if self.next_quad() => continue,
+                None => return None,
+            };
+
+            // Points on an axis belong to the quadrant that starts at that axis
+            if (self.quad % 2 == 1 && pt.x() == 0) || (self.quad % 2 == 0 && pt.y() == 0) {
+                continue;
+            }
+
+            let pt = quad_to_real(pt, self.quad, self.c);
+            if self.range.contains(pt.into(), self.c.into()) {
+                return Some(pt);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conics::Ellipse;
+    use crate::RADS;
+
+    #[test]
+    fn ellipse_arc() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let xr = 190;
+        let yr = 120;
+        let c = (200, 200);
+        let start = RADS * 1.8;
+        let end = RADS * 0.5;
+
+        let mut image = crate::test::img::setup_ellipse(xr, yr, c);
+
+        super::ellipse_arc(
+            &mut image,
+            start,
+            end,
+            xr,
+            yr,
+            c,
+            image::Rgba([255, 0, 0, 255]),
+        );
+
+        image.save("images/ellipse_arc.png")
+    }
+
+    #[test]
+    fn ellipse_arc_quadrants() {
+        let (xr, yr, c) = (57, 23, (100, 100));
+        let mut full: Vec<Pt<i32>> = Ellipse::new(xr, yr, c).collect();
+        full.sort();
+
+        let mut pts: Vec<Pt<i32>> = EllipseArc::new(45, 45, xr, yr, c).collect();
+        pts.sort();
+        assert_eq!(full, pts);
+
+        // Consecutive arcs should cover the whole ellipse
+        let mut pts: Vec<Pt<i32>> = [(0, 90), (90, 200), (200, 270), (270, 360)]
+            .into_iter()
+            .flat_map(|(s, e)| EllipseArc::new(s, e, xr, yr, c))
+            .collect();
+        pts.sort();
+        pts.dedup();
+        assert_eq!(full, pts);
+    }
+
+    #[test]
+    fn ellipse_arc_order() {
+        // Counter-clockwise angle of a point around the center
+        let angle = |pt: Pt<i32>| {
+            crate::angle::normalize(f64::from(100 - pt.y()).atan2(f64::from(pt.x() - 100)))
+        };
+
+        // In order from the start angle in 0°-90° and 180°-270°, reversed otherwise
+        for (start, end, in_order) in [
+            (10, 80, true),
+            (100, 170, false),
+            (190, 260, true),
+            (280, 350, false),
+        ] {
+            let pts: Vec<Pt<i32>> = EllipseArc::new(start, end, 57, 23, (100, 100)).collect();
+            let (first, last) = (angle(pts[0]), angle(pts[pts.len() - 1]));
+            assert_eq!(first < last, in_order, "{start}-{end}");
+        }
+    }
+
+    #[test]
+    fn rotated_ellipse_arc() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let mut image = crate::test::img::blank(Pt::new(crate::IMG_SIZE, crate::IMG_SIZE));
+
+        EllipseArc::rotated(RADS * 1.8, RADS * 0.5, 190, 80, RADS, (200, 200))
+            .draw(&mut image, image::Rgba([255, 0, 0, 255]));
+
+        image.save("images/rotated_ellipse_arc.png")
+    }
+
+    #[test]
+    fn rotated_ellipse_arc_quadrants() {
+        let (xr, yr, c) = (57, 23, (100, 100));
+        let mut full: Vec<Pt<i32>> = Ellipse::rotated(xr, yr, 30, c).collect();
+        full.sort();
+
+        let mut pts: Vec<Pt<i32>> = [(0, 90), (90, 200), (200, 270), (270, 360)]
+            .into_iter()
+            .flat_map(|(s, e)| EllipseArc::rotated(s, e, xr, yr, 30, c))
+            .collect();
+        pts.sort();
+        pts.dedup();
+        assert_eq!(full, pts);
+
+        // The arc should rotate with the ellipse
+        let arc: Vec<Pt<i32>> = EllipseArc::rotated(-5, 5, xr, yr, 30, c).collect();
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let tip = Pt::new(100.0 + 57.0 * cos, 100.0 - 57.0 * sin);
+        assert!(
+            arc.iter()
+                .any(|pt| (pt.x() as f64 - tip.x()).hypot(pt.y() as f64 - tip.y()) < 1.0),
+            "{tip} not found in {arc:?}"
+        );
+    }
+}
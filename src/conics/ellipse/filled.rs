@@ -0,0 +1,239 @@
+use super::{radii, AngleRange, Pos};
+use crate::Pt;
+
+/// Draws a filled axis-aligned ellipse.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::ellipse_filled;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// ellipse_filled(&mut image, 190, 120, (200, 200), color);
+/// ```
+///
+/// The interior is drawn using horizontal spans that end at the same pixels
+/// drawn by [`ellipse`](crate::conics::ellipse).
+///
+/// # Panics
+///
+/// Panics if either radius is less than or equal to 0
+///
+/// See also: [`Draw::ellipse_filled`](crate::Draw::ellipse_filled)
+///
+pub fn ellipse_filled<C, I, T>(image: &mut I, x_radius: T, y_radius: T, center: C, color: I::Pixel)
+where
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    let c = Pt::new(center.x().into(), center.y().into());
+    let (xr, yr) = radii(x_radius, y_radius);
+
+    for (x, y) in Spans::new(xr, yr) {
+        put_span(image, c.x() - x, c.x() + x, c.y() - y, color);
+        if y != 0 {
+            put_span(image, c.x() - x, c.x() + x, c.y() + y, color);
+        }
+    }
+}
+
+/// Draws a filled elliptical pie slice.
+///
+/// The slice is drawn from the center of the ellipse out to its edge, between
+/// the start and end angles.  Angles are measured from the center of the
+/// ellipse, the same as with [`ellipse_arc`](crate::conics::ellipse_arc).
+///
+/// A floating-point angle will represent an angle in radians.  Integer types
+/// will represent an angle in degrees.
+///
+/// # Example
+///
+/// ```
+/// use image::{RgbaImage, Rgba};
+/// use freehand::conics::ellipse_pie_slice_filled;
+///
+/// let bg = Rgba([255, 255, 255, 255]); // white
+/// let color = Rgba([255, 0, 0, 255]);
+/// let mut image = RgbaImage::from_pixel(400, 400, bg);
+///
+/// // A slice from 0° to 55°
+/// ellipse_pie_slice_filled(&mut image, 0, 55, 190, 120, (200, 200), color);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either radius is less than or equal to 0
+///
+/// See also: [`Draw::ellipse_pie_slice_filled`](crate::Draw::ellipse_pie_slice_filled)
+///
+pub fn ellipse_pie_slice_filled<A, C, I, T>(
+    image: &mut I,
+    start_angle: A,
+    end_angle: A,
+    x_radius: T,
+    y_radius: T,
+    center: C,
+    color: I::Pixel,
+) where
+    A: crate::Angle,
+    C: crate::pt::Point<T>,
+    I: image::GenericImage,
+    T: Into<i32> + Copy,
+{
+    let c = Pt::new(center.x().into(), center.y().into());
+    let (xr, yr) = radii(x_radius, y_radius);
+    let range = AngleRange::new(start_angle, end_angle);
+
+    for (x, y) in Spans::new(xr, yr) {
+        slice_span(image, &range, c.x() - x, c.x() + x, c.y() - y, c, color);
+        if y != 0 {
+            slice_span(image, &range, c.x() - x, c.x() + x, c.y() + y, c, color);
+        }
+    }
+}
+
+/// Draws the parts of a horizontal span that are inside of a range of angles.
+fn slice_span<I: image::GenericImage>(
+    image: &mut I,
+    range: &AngleRange,
+    x1: i32,
+    x2: i32,
+    y: i32,
+    c: Pt<i32>,
+    color: I::Pixel,
+) {
+    let mut start = None;
+
+    for x in x1..=x2 {
+        // The center is always part of the slice
+        let inside = (x == c.x() && y == c.y()) || range.contains(Pt::new(x, y).into(), c.into());
+
+        match (start, inside) {
+            (None, true) => start = Some(x),
+            (Some(s), false) => {
+                put_span(image, s, x - 1, y, color);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(s) = start {
+        put_span(image, s, x2, y, color);
+    }
+}
+
+/// Draws a horizontal line between two x coordinates.  Ignores points outside of the image.
+fn put_span<I: image::GenericImage>(image: &mut I, x1: i32, x2: i32, y: i32, color: I::Pixel) {
+    let width = image.width() as i64;
+    let height = image.height() as i64;
+
+    if !(0..height).contains(&(y as i64)) {
+        return;
+    }
+
+    let x1 = (x1 as i64).max(0);
+    let x2 = (x2 as i64).min(width - 1);
+
+    for x in x1..=x2 {
+        // Safe because the coordinates have been clamped to the image bounds
+        unsafe {
+            image.unsafe_put_pixel(x as u32, y as u32, color);
+        }
+    }
+}
+
+/// Iterates over the rows of a single quadrant of an ellipse.
+///
+/// Returns a local y coordinate with the widest local x coordinate in that row.
+#[derive(Clone, Debug)]
+struct Spans {
+    pos: Pos,
+    last_y: i32,
+}
+
+impl Spans {
+    fn new(xr: i32, yr: i32) -> Self {
+        Self {
+            pos: Pos::new(xr, yr),
+            last_y: -1,
+        }
+    }
+}
+
+impl Iterator for Spans {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pt = self.pos.step()?;
+            // Points are returned from the widest x coordinate first
+            if pt.y() != self.last_y {
+                self.last_y = pt.y();
+                return Some((pt.x(), pt.y()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conics::Ellipse;
+    use crate::test::color_in_image;
+    use crate::RADS;
+
+    #[test]
+    fn ellipse_filled() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let (xr, yr, c) = (190, 120, (200, 200));
+        let mut image = crate::test::img::setup_ellipse(xr, yr, c);
+
+        super::ellipse_filled(&mut image, xr, yr, c, image::Rgba([255, 0, 0, 255]));
+
+        image.save("images/ellipse_filled.png")
+    }
+
+    #[test]
+    fn ellipse_filled_overwrite_outline() {
+        let mut image = crate::test::img::blank((200, 200));
+        let error = image::Rgba([0, 0, 255, 255]);
+        let color = image::Rgba([255, 0, 0, 255]);
+        let (xr, yr, c) = (90, 37, (100, 100));
+
+        Ellipse::new(xr, yr, c).draw(&mut image, error);
+        super::ellipse_filled(&mut image, xr, yr, c, color);
+
+        if let Some((x, y)) = color_in_image(&image, error) {
+            let _ = image.save("images/tests/failed_ellipse_filled_overwrite_outline.png");
+            panic!("{:?} found in image at ({}, {})", error.0, x, y);
+        }
+    }
+
+    #[test]
+    fn ellipse_pie_slice() -> Result<(), image::ImageError> {
+        crate::logger(crate::LOG_LEVEL);
+
+        let (xr, yr, c) = (190, 120, (200, 200));
+        let start = RADS * 1.8;
+        let end = RADS * 0.5;
+        let mut image = crate::test::img::setup_ellipse(xr, yr, c);
+
+        super::ellipse_pie_slice_filled(
+            &mut image,
+            start,
+            end,
+            xr,
+            yr,
+            c,
+            image::Rgba([255, 0, 0, 255]),
+        );
+
+        image.save("images/ellipse_pie_slice.png")
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Pt;
+
+/// Steps through a single quadrant of an axis-aligned ellipse.
+///
+/// Points are returned in local coordinates (both positive) starting at
+/// `(xr, 0)` and ending at `(0, yr)`.
+// http://members.chello.at/~easyfilter/bresenham.html
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub(super) struct Pos {
+    /// Negated local x coordinate (counts up from `-xr` to 0)
+    x: i64,
+    /// Local y coordinate (counts up from 0 to `yr`)
+    y: i64,
+    /// Decision parameter
+    err: i64,
+    /// Horizontal radius squared
+    a2: i64,
+    /// Vertical radius squared
+    b2: i64,
+    /// Vertical radius
+    b: i64,
+    /// Last y coordinate returned.  Used to finish the tips of very flat ellipses.
+    last_y: i64,
+}
+
+impl Pos {
+    pub(super) fn new(xr: i32, yr: i32) -> Self {
+        let a = xr as i64;
+        let b = yr as i64;
+        let x = -a;
+        let b2 = b * b;
+
+        Self {
+            x,
+            y: 0,
+            err: x * (2 * b2 + x) + b2,
+            a2: a * a,
+            b2,
+            b,
+            last_y: -1,
+        }
+    }
+
+    /// Returns the next point in the quadrant, or `None` once `(0, yr)` has been reached.
+    pub(super) fn step(&mut self) -> Option<Pt<i32>> {
+        if self.x <= 0 {
+            let pt = Pt::new(-self.x as i32, self.y as i32);
+            self.last_y = self.y;
+
+            let e2 = 2 * self.err;
+            if e2 >= (self.x * 2 + 1) * self.b2 {
+                self.x += 1;
+                self.err += (self.x * 2 + 1) * self.b2;
+            }
+            if e2 <= (self.y * 2 + 1) * self.a2 {
+                self.y += 1;
+                self.err += (self.y * 2 + 1) * self.a2;
+            }
+
+            Some(pt)
+        } else if self.last_y < self.b {
+            // very flat ellipses stop early - finish the tip with a vertical line
+            self.last_y += 1;
+            Some(Pt::new(0, self.last_y as i32))
+        } else {
+            None
+        }
+    }
+}
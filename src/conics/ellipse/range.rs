@@ -0,0 +1,39 @@
+use crate::{angle, Pt};
+
+/// A range of angles going counter-clockwise from a start angle to an end angle.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+pub(super) struct AngleRange {
+    /// Start angle in radians
+    pub(super) start: f64,
+    /// End angle in radians
+    pub(super) end: f64,
+}
+
+impl AngleRange {
+    /// Normalizes the angles.  If the start and end angles are the same the
+    /// range will cover a full circle.
+    pub(super) fn new<A: crate::Angle>(start_angle: A, end_angle: A) -> Self {
+        let start = angle::normalize(start_angle.radians());
+        let mut end = angle::normalize(end_angle.radians());
+        if (start - end).abs() <= f64::EPSILON {
+            end = angle::normalize(start - crate::TINY);
+        }
+        Self { start, end }
+    }
+
+    /// Checks whether a point lies between the start and end angles, as seen from the center.
+    pub(super) fn contains(&self, pt: Pt<f64>, c: Pt<f64>) -> bool {
+        let angle = angle::normalize((c.y() - pt.y()).atan2(pt.x() - c.x()));
+
+        // Allow for a small amount of error from converting degrees to radians
+        let after_start = angle >= self.start - crate::TINY;
+        let before_end = angle <= self.end + crate::TINY;
+
+        if self.start <= self.end {
+            after_start && before_end
+        } else {
+            after_start || before_end
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::Pt;
+
+/// The implicit equation of a rotated ellipse, `a*x² + b*x*y + c*y² = f`,
+/// using coordinates relative to the center of the ellipse.
+///
+/// Methods take and return image coordinates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Curve {
+    a: f64,
+    b: f64,
+    c: f64,
+    f: f64,
+    /// Center of the ellipse
+    center: Pt<f64>,
+    /// Horizontal distance from the center to the leftmost and rightmost points
+    x_ext: f64,
+    /// Vertical distance from the center to the topmost and bottommost points
+    y_ext: f64,
+}
+
+impl Curve {
+    /// Creates the equation of an ellipse rotated counter-clockwise by `rotation` radians.
+    pub(super) fn new(xr: f64, yr: f64, rotation: f64, center: Pt<f64>) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+        let (xr2, yr2) = (xr * xr, yr * yr);
+
+        let a = yr2 * cos * cos + xr2 * sin * sin;
+        let c = yr2 * sin * sin + xr2 * cos * cos;
+        // The y axis points down in an image, which flips the sign of the xy term
+        let b = 2.0 * sin * cos * (xr2 - yr2);
+
+        Self {
+            a,
+            b,
+            c,
+            f: xr2 * yr2,
+            center,
+            x_ext: c.sqrt(),
+            y_ext: a.sqrt(),
+        }
+    }
+
+    /// Returns the first and last columns that the ellipse crosses.
+    pub(super) fn columns(&self) -> (i32, i32) {
+        let x = self.center.x();
+        (
+            (x - self.x_ext).ceil() as i32,
+            (x + self.x_ext).floor() as i32,
+        )
+    }
+
+    /// Returns the first and last rows that the ellipse crosses.
+    pub(super) fn rows(&self) -> (i32, i32) {
+        let y = self.center.y();
+        (
+            (y - self.y_ext).ceil() as i32,
+            (y + self.y_ext).floor() as i32,
+        )
+    }
+
+    /// Returns the two y coordinates where the ellipse crosses a column.
+    pub(super) fn ys(&self, x: f64) -> [f64; 2] {
+        let x = x - self.center.x();
+        let disc = (self.b * self.b * x * x - 4.0 * self.c * (self.a * x * x - self.f)).max(0.0);
+        let sq = disc.sqrt();
+        [
+            (-self.b * x - sq) / (2.0 * self.c) + self.center.y(),
+            (-self.b * x + sq) / (2.0 * self.c) + self.center.y(),
+        ]
+    }
+
+    /// Returns the two x coordinates where the ellipse crosses a row.
+    pub(super) fn xs(&self, y: f64) -> [f64; 2] {
+        let y = y - self.center.y();
+        let disc = (self.b * self.b * y * y - 4.0 * self.a * (self.c * y * y - self.f)).max(0.0);
+        let sq = disc.sqrt();
+        [
+            (-self.b * y - sq) / (2.0 * self.a) + self.center.x(),
+            (-self.b * y + sq) / (2.0 * self.a) + self.center.x(),
+        ]
+    }
+
+    /// Checks whether the slope of the ellipse at a point on the ellipse is
+    /// between -1 and 1.
+    pub(super) fn flat(&self, x: f64, y: f64) -> bool {
+        let x = x - self.center.x();
+        let y = y - self.center.y();
+        // Compare the partial derivatives
+        (2.0 * self.a * x + self.b * y).abs() <= (self.b * x + 2.0 * self.c * y).abs()
+    }
+
+    /// Checks whether a point found on a row should be drawn by the row: the
+    /// ellipse has to be closer to vertical where it crosses the nearest column.
+    ///
+    /// Checking the slope at the column instead of at the point itself keeps
+    /// gaps from forming where the slope of the ellipse passes through 1.
+    pub(super) fn steep(&self, x: f64, y: f64) -> bool {
+        let px = x.round();
+        let [y1, y2] = self.ys(px);
+        let yc = if (y1 - y).abs() <= (y2 - y).abs() {
+            y1
+        } else {
+            y2
+        };
+        !self.flat(px, yc)
+    }
+}
+
+/// Iterates over the pixels of a rotated ellipse.
+///
+/// Columns are visited first, returning the pixels where the ellipse is closer
+/// to horizontal, then rows are visited for the pixels where it is closer to
+/// vertical.  Each pixel is only returned once.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub(super) struct Pos {
+    curve: Curve,
+    /// Next column
+    x: i32,
+    /// Last column
+    x_end: i32,
+    /// Next row
+    y: i32,
+    /// Last row
+    y_end: i32,
+    /// Second point found in the last column or row
+    pending: Option<Pt<i32>>,
+}
+
+impl Pos {
+    pub(super) fn new(xr: f64, yr: f64, rotation: f64, center: Pt<f64>) -> Self {
+        let curve = Curve::new(xr, yr, rotation, center);
+        let (x, x_end) = curve.columns();
+        let (y, y_end) = curve.rows();
+
+        Self {
+            curve,
+            x,
+            x_end,
+            y,
+            y_end,
+            pending: None,
+        }
+    }
+
+    /// Returns the rows of the pixels in a column where the ellipse is closer to horizontal.
+    fn column(&self, x: i32) -> [Option<i32>; 2] {
+        let [y1, y2] = self.curve.ys(x as f64);
+        let flat = |y: f64| self.curve.flat(x as f64, y).then(|| y.round() as i32);
+
+        let (a, b) = (flat(y1), flat(y2));
+        if a == b {
+            [a, None]
+        } else {
+            [a, b]
+        }
+    }
+
+    /// Returns the columns of the pixels in a row where the ellipse is closer
+    /// to vertical, skipping any pixels already returned by [`Pos::column`].
+    fn row(&self, y: i32) -> [Option<i32>; 2] {
+        let [x1, x2] = self.curve.xs(y as f64);
+        let steep = |x: f64| {
+            let px = x.round() as i32;
+            (self.curve.steep(x, y as f64) && !self.column(px).contains(&Some(y))).then(|| px)
+        };
+
+        let (a, b) = (steep(x1), steep(x2));
+        if a == b {
+            [a, None]
+        } else {
+            [a, b]
+        }
+    }
+}
+
+impl Iterator for Pos {
+    type Item = Pt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pt) = self.pending.take() {
+            return Some(pt);
+        }
+
+        loop {
+            let pts = if self.x <= self.x_end {
+                let x = self.x;
+                self.x += 1;
+                self.column(x).map(|y| y.map(|y| Pt::new(x, y)))
+            } else if self.y <= self.y_end {
+                let y = self.y;
+                self.y += 1;
+                self.row(y).map(|x| x.map(|x| Pt::new(x, y)))
+            } else {
+                return None;
+            };
+
+            match pts {
+                [Some(a), b] => {
+                    self.pending = b;
+                    return Some(a);
+                }
+                [None, Some(b)] => return Some(b),
+                [None, None] => {}
+            }
+        }
+    }
+}
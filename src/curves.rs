@@ -0,0 +1,19 @@
+//! Curves: quadratic and cubic Bézier curves, Catmull-Rom splines, and B-splines, with
+//! antialiased variants.
+//!
+//! ```
+//! # use image::{RgbaImage, Rgba};
+//! use freehand::curves::cubic_bezier;
+//! # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+//!
+//! cubic_bezier(&mut image, (20, 380), (100, 20), (300, 20), (380, 380), Rgba([255, 0, 0, 255]));
+//! ```
+
+mod bezier;
+mod spline;
+
+pub(crate) use bezier::flatten;
+pub use bezier::{
+    antialiased_cubic_bezier, antialiased_quadratic_bezier, cubic_bezier, quadratic_bezier, Bezier,
+};
+pub use spline::{antialiased_b_spline, antialiased_catmull_rom, b_spline, catmull_rom};
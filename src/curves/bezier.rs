@@ -0,0 +1,410 @@
+use crate::lines::{polyline_stroke, LineCap, LineJoin};
+use crate::{Point, Pt};
+
+/// Smallest step along a curve, to stop halving the step forever
+const MIN_STEP: f64 = 1e-12;
+
+/// An iterator over the pixels of a quadratic or cubic Bézier curve.
+///
+/// The step along the curve adapts to its curvature: it is halved whenever
+/// the next pixel would leave a gap and doubled whenever it would land on the
+/// same pixel again.  Pixels that only connect two diagonal neighbors (the
+/// corner of an L shape) are skipped, so every pixel touches the ones before
+/// and after it and no pixel is repeated.
+///
+/// # Example
+///
+/// ```
+/// use freehand::curves::Bezier;
+///
+/// for pt in Bezier::quadratic((10, 380), (200, 20), (390, 380)) {
+///     // pt is a Pt<i32>
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Bezier {
+    /// Control points of the curve, quadratic curves are raised to cubic
+    ctrl: [Pt<f64>; 4],
+    /// Position along the curve, from 0.0 to 1.0
+    t: f64,
+    /// Current step along the curve
+    dt: f64,
+    /// Last pixel found along the curve
+    cur: Pt<i32>,
+    /// Last pixel returned
+    prev: Option<Pt<i32>>,
+    /// Next pixel to return, unless it turns out to be a corner
+    pending: Option<Pt<i32>>,
+}
+
+impl Bezier {
+    /// Creates an iterator over a quadratic Bézier curve from `a` to `b` with
+    /// the control point `c`.
+    pub fn quadratic<P, T>(a: P, c: P, b: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (a, c, b) = (to_f64(&a), to_f64(&c), to_f64(&b));
+        // The same curve as a cubic, with both control points 2/3 of the way to c
+        let c1 = a + (c - a).mul(2.0 / 3.0);
+        let c2 = b + (c - b).mul(2.0 / 3.0);
+        Self::from_ctrl([a, c1, c2, b])
+    }
+
+    /// Creates an iterator over a cubic Bézier curve from `a` to `b` with the
+    /// control points `c1` and `c2`.
+    pub fn cubic<P, T>(a: P, c1: P, c2: P, b: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        Self::from_ctrl([to_f64(&a), to_f64(&c1), to_f64(&c2), to_f64(&b)])
+    }
+
+    /// Creates an iterator over a cubic Bézier curve from its control points.
+    pub(crate) fn from_ctrl(ctrl: [Pt<f64>; 4]) -> Self {
+        let start = ctrl[0].i32();
+        Self {
+            ctrl,
+            t: 0.0,
+            // Roughly one pixel per step, the curve is never longer than its hull
+            dt: 1.0 / (hull_len(&ctrl) + 1.0),
+            cur: start,
+            prev: None,
+            pending: Some(start),
+        }
+    }
+
+    /// Steps along the curve to the next pixel that is not the current one.
+    fn step(&mut self) -> Option<Pt<i32>> {
+        while self.t < 1.0 {
+            let t = (self.t + self.dt).min(1.0);
+            let pt = point_at(&self.ctrl, t).i32();
+            let dist = (pt.x() - self.cur.x())
+                .abs()
+                .max((pt.y() - self.cur.y()).abs());
+
+            if dist > 1 && self.dt > MIN_STEP {
+                self.dt /= 2.0;
+                continue;
+            }
+
+            self.t = t;
+            if dist == 0 {
+                self.dt *= 2.0;
+                continue;
+            }
+
+            self.cur = pt;
+            return Some(pt);
+        }
+        None
+    }
+}
+
+impl Iterator for Bezier {
+    type Item = Pt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pending = self.pending?;
+            let next = if let Some(next) = self.step() {
+                next
+            } else {
+                self.pending = None;
+                return Some(pending);
+            };
+
+            if let Some(prev) = self.prev {
+                let dist = (next.x() - prev.x()).abs().max((next.y() - prev.y()).abs());
+                if dist == 0 {
+                    // The curve went back to the last pixel, skip over both
+                    self.pending = self.step();
+                    continue;
+                } else if dist == 1 {
+                    // The pending pixel is a corner
+                    self.pending = Some(next);
+                    continue;
+                }
+            }
+
+            self.prev = Some(pending);
+            self.pending = Some(next);
+            return Some(pending);
+        }
+    }
+}
+
+/// The exact point at `t` along a cubic Bézier curve.
+fn point_at(ctrl: &[Pt<f64>; 4], t: f64) -> Pt<f64> {
+    let [p0, p1, p2, p3] = *ctrl;
+    let s = 1.0 - t;
+    p0.mul(s * s * s) + p1.mul(3.0 * s * s * t) + p2.mul(3.0 * s * t * t) + p3.mul(t * t * t)
+}
+
+pub(super) fn to_f64<P, T>(pt: &P) -> Pt<f64>
+where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    Pt::new(pt.x().into(), pt.y().into())
+}
+
+/// Length of the lines between the control points.
+fn hull_len(ctrl: &[Pt<f64>]) -> f64 {
+    ctrl.windows(2)
+        .map(|w| (w[1].x() - w[0].x()).hypot(w[1].y() - w[0].y()))
+        .sum()
+}
+
+/// Draws a quadratic Bézier curve from `a` to `b` with the control point
+/// `c`.
+///
+/// Points that are outside of the image bounds are ignored.  See
+/// [`Bezier`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::quadratic_bezier;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// quadratic_bezier(&mut image, (10, 380), (200, 20), (390, 380), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::quadratic_bezier`](crate::Draw::quadratic_bezier)
+///
+pub fn quadratic_bezier<I, P, T>(image: &mut I, a: P, c: P, b: P, color: I::Pixel)
+where
+    I: image::GenericImage,
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    crate::draw_iter(image, Bezier::quadratic(a, c, b), color);
+}
+
+/// Draws a cubic Bézier curve from `a` to `b` with the control points `c1`
+/// and `c2`.
+///
+/// Points that are outside of the image bounds are ignored.  See
+/// [`Bezier`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::cubic_bezier;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// cubic_bezier(&mut image, (20, 380), (100, 20), (300, 20), (380, 380), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::cubic_bezier`](crate::Draw::cubic_bezier)
+///
+pub fn cubic_bezier<I, P, T>(image: &mut I, a: P, c1: P, c2: P, b: P, color: I::Pixel)
+where
+    I: image::GenericImage,
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    crate::draw_iter(image, Bezier::cubic(a, c1, c2, b), color);
+}
+
+/// Draws an antialiased quadratic Bézier curve from `a` to `b` with the
+/// control point `c` that is `width` pixels wide.
+///
+/// The curve is split into short lines that are drawn with
+/// [`polyline_stroke`], so every pixel is blended once.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::antialiased_quadratic_bezier;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_quadratic_bezier(
+///     &mut image,
+///     (10.0, 380.0),
+///     (200.0, 20.5),
+///     (390.0, 380.0),
+///     2.5,
+///     Rgba([255, 0, 0, 255]),
+/// );
+/// ```
+///
+/// See also: [`Draw::antialiased_quadratic_bezier`](crate::Draw::antialiased_quadratic_bezier)
+///
+pub fn antialiased_quadratic_bezier<P, T>(
+    image: &mut image::RgbaImage,
+    a: P,
+    c: P,
+    b: P,
+    width: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    stroke(image, &[Bezier::quadratic(a, c, b).ctrl], width, color);
+}
+
+/// Draws an antialiased cubic Bézier curve from `a` to `b` with the control
+/// points `c1` and `c2` that is `width` pixels wide.
+///
+/// The curve is split into short lines that are drawn with
+/// [`polyline_stroke`], so every pixel is blended once.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::antialiased_cubic_bezier;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_cubic_bezier(
+///     &mut image,
+///     (20.0, 380.0),
+///     (100.0, 20.0),
+///     (300.5, 20.0),
+///     (380.0, 380.0),
+///     3.0,
+///     Rgba([255, 0, 0, 255]),
+/// );
+/// ```
+///
+/// See also: [`Draw::antialiased_cubic_bezier`](crate::Draw::antialiased_cubic_bezier)
+///
+pub fn antialiased_cubic_bezier<P, T>(
+    image: &mut image::RgbaImage,
+    a: P,
+    c1: P,
+    c2: P,
+    b: P,
+    width: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    stroke(image, &[Bezier::cubic(a, c1, c2, b).ctrl], width, color);
+}
+
+/// Points along a cubic Bézier curve that are at most 2px apart, after its
+/// first point.
+pub(crate) fn flatten(ctrl: &[Pt<f64>; 4]) -> impl Iterator<Item = Pt<f64>> + '_ {
+    let n = (hull_len(ctrl) / 2.0).ceil().max(1.0) as u32;
+    (1..=n).map(move |i| point_at(ctrl, f64::from(i) / f64::from(n)))
+}
+
+/// Strokes curves that join end to end as lines that are at most 2px long.
+pub(super) fn stroke(
+    image: &mut image::RgbaImage,
+    curves: &[[Pt<f64>; 4]],
+    width: f32,
+    color: image::Rgba<u8>,
+) {
+    let mut points: Vec<_> = curves.first().map(|ctrl| ctrl[0]).into_iter().collect();
+    for ctrl in curves {
+        points.extend(flatten(ctrl));
+    }
+    polyline_stroke(
+        image,
+        points,
+        width,
+        LineJoin::Round,
+        LineCap::Butt,
+        false,
+        color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bezier;
+    use crate::Pt;
+
+    fn curves() -> Vec<Bezier> {
+        vec![
+            Bezier::quadratic((10, 380), (200, 20), (390, 380)),
+            Bezier::quadratic((5, 5), (300, 10), (20, 390)),
+            Bezier::quadratic(Pt::new(3.5, 7.25), Pt::new(3.5, 7.25), Pt::new(90.0, 40.5)),
+            Bezier::cubic((20, 380), (100, 20), (300, 20), (380, 380)),
+            // Loops back over itself
+            Bezier::cubic((50, 200), (400, 0), (0, 0), (350, 200)),
+            // A cusp
+            Bezier::cubic((50, 300), (300, 50), (100, 50), (350, 300)),
+            Bezier::cubic((200, 200), (200, 200), (200, 200), (200, 200)),
+        ]
+    }
+
+    #[test]
+    fn bezier() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        for (i, curve) in (0..).zip(curves()) {
+            let color = image::Rgba([40 * i as u8, 0, 255 - 40 * i as u8, 255]);
+            crate::draw_iter(&mut image, curve, color);
+        }
+        image.save("images/bezier.png")
+    }
+
+    #[test]
+    fn antialiased_bezier() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let color = image::Rgba([255, 0, 0, 255]);
+        super::antialiased_quadratic_bezier(
+            &mut image,
+            (10.0, 380.0),
+            (200.0, 20.5),
+            (390.0, 380.0),
+            2.5,
+            color,
+        );
+        super::antialiased_cubic_bezier(
+            &mut image,
+            (20.0, 300.0),
+            (100.0, 20.0),
+            (300.5, 20.0),
+            (380.0, 300.0),
+            5.0,
+            color,
+        );
+        image.save("images/antialiased_bezier.png")
+    }
+
+    #[test]
+    fn bezier_connected() {
+        let dist = |a: Pt<i32>, b: Pt<i32>| (a.x() - b.x()).abs().max((a.y() - b.y()).abs());
+
+        for curve in curves() {
+            let start = curve.ctrl[0].i32();
+            let end = curve.ctrl[3].i32();
+            let pts: Vec<_> = curve.collect();
+
+            assert_eq!(pts.first(), Some(&start));
+            assert_eq!(pts.last(), Some(&end));
+            // No gaps or repeated pixels
+            for w in pts.windows(2) {
+                assert_eq!(dist(w[0], w[1]), 1, "{w:?}");
+            }
+            // No corners
+            for w in pts.windows(3) {
+                assert!(dist(w[0], w[2]) > 1, "{w:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn quadratic_as_line() {
+        // A quadratic curve with its control point on the line is the line
+        let pts: Vec<_> = Bezier::quadratic((0, 0), (20, 10), (40, 20)).collect();
+        assert_eq!(pts.len(), 41);
+        for (x, pt) in (0..).zip(&pts) {
+            assert_eq!(pt.x(), x);
+            assert!((f64::from(pt.y()) - f64::from(x) / 2.0).abs() <= 0.5);
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use super::bezier::{stroke, to_f64, Bezier};
+use crate::{Point, Pt};
+use image::GenericImage;
+
+/// The cubic Bézier curves between each pair of points of a cardinal spline.
+///
+/// Each point's tangent points from the point before it to the point after
+/// it, scaled by `1.0 - tension`.  The first and last points are repeated so
+/// the spline reaches both ends.
+fn cardinal_curves(points: &[Pt<f64>], tension: f32) -> Vec<[Pt<f64>; 4]> {
+    let scale = (1.0 - f64::from(tension)) / 2.0;
+    let last = points.len().saturating_sub(1);
+    let tangent = |i: usize| (points[(i + 1).min(last)] - points[i.saturating_sub(1)]).mul(scale);
+
+    (0..last)
+        .map(|i| {
+            let (a, b) = (points[i], points[i + 1]);
+            [a, a + tangent(i).div(3.0), b - tangent(i + 1).div(3.0), b]
+        })
+        .collect()
+}
+
+/// The cubic Bézier curves of a uniform cubic B-spline.
+///
+/// The first and last points are tripled so the spline starts and ends on
+/// them instead of somewhere near them.
+fn b_spline_curves(points: &[Pt<f64>]) -> Vec<[Pt<f64>; 4]> {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Vec::new(),
+    };
+
+    let mut pts = vec![first, first];
+    pts.extend_from_slice(points);
+    pts.extend([last, last]);
+
+    pts.windows(4)
+        .map(|w| {
+            let [p0, p1, p2, p3] = [w[0], w[1], w[2], w[3]];
+            [
+                (p0 + p1.mul(4.0) + p2).div(6.0),
+                (p1.mul(2.0) + p2).div(3.0),
+                (p1 + p2.mul(2.0)).div(3.0),
+                (p1 + p2.mul(4.0) + p3).div(6.0),
+            ]
+        })
+        .collect()
+}
+
+/// The pixels of curves that join end to end, without repeated pixels or
+/// corners where the curves meet.
+fn pixels(curves: &[[Pt<f64>; 4]]) -> Vec<Pt<i32>> {
+    let dist = |a: Pt<i32>, b: Pt<i32>| (a.x() - b.x()).abs().max((a.y() - b.y()).abs());
+
+    let mut pixels: Vec<Pt<i32>> = Vec::new();
+    for &ctrl in curves {
+        for pt in Bezier::from_ctrl(ctrl) {
+            match pixels.len() {
+                n if n > 0 && pixels[n - 1] == pt => continue,
+                n if n > 1 && dist(pixels[n - 2], pt) == 0 => {
+                    pixels.pop();
+                    continue;
+                }
+                n if n > 1 && dist(pixels[n - 2], pt) == 1 => {
+                    pixels.pop();
+                }
+                _ => {}
+            }
+            pixels.push(pt);
+        }
+    }
+    pixels
+}
+
+/// Collects the points of a spline.
+fn collect<P, T, It>(points: It) -> Vec<Pt<f64>>
+where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    It: IntoIterator<Item = P>,
+{
+    points.into_iter().map(|p| to_f64(&p)).collect()
+}
+
+/// Draws a smooth curve through each of the points using a cardinal spline.
+///
+/// A `tension` of 0.0 draws a Catmull-Rom spline and a tension of 1.0 draws
+/// straight lines from one point to the next, like [`path`](crate::lines::path).
+/// The start and end points are not connected.  Points that are outside of the
+/// image bounds are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::catmull_rom;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let points = [(10, 300), (100, 120), (200, 250), (300, 50), (390, 200)];
+/// catmull_rom(&mut image, points, 0.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::catmull_rom`](crate::Draw::catmull_rom)
+///
+pub fn catmull_rom<I, P, It>(image: &mut I, points: It, tension: f32, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let points = collect(points);
+    let curves = cardinal_curves(&points, tension);
+    if curves.is_empty() {
+        // There is at most one point
+        crate::draw_iter(image, points.iter().map(Pt::<f64>::i32), color);
+        return;
+    }
+    crate::draw_iter(image, pixels(&curves).into_iter(), color);
+}
+
+/// Draws a smooth curve using a uniform cubic B-spline with the points as
+/// its control points.
+///
+/// The curve starts on the first point and ends on the last point but only
+/// passes near the points in between, which smooths out noisy data.  Points
+/// that are outside of the image bounds are ignored.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::b_spline;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let points = [(10, 300), (100, 120), (200, 250), (300, 50), (390, 200)];
+/// b_spline(&mut image, points, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::b_spline`](crate::Draw::b_spline)
+///
+pub fn b_spline<I, P, It>(image: &mut I, points: It, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let curves = b_spline_curves(&collect(points));
+    crate::draw_iter(image, pixels(&curves).into_iter(), color);
+}
+
+/// Draws an antialiased smooth curve that is `width` pixels wide through each
+/// of the points using a cardinal spline.
+///
+/// See [`catmull_rom`] for how `tension` changes the curve.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::antialiased_catmull_rom;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let points = [(10.0, 300.0), (100.5, 120.0), (200.0, 250.0), (390.0, 200.0)];
+/// antialiased_catmull_rom(&mut image, points, 0.5, 2.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_catmull_rom`](crate::Draw::antialiased_catmull_rom)
+///
+pub fn antialiased_catmull_rom<P, T, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    tension: f32,
+    width: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    It: IntoIterator<Item = P>,
+{
+    let curves = cardinal_curves(&collect(points), tension);
+    stroke(image, &curves, width, color);
+}
+
+/// Draws an antialiased smooth curve that is `width` pixels wide using a
+/// uniform cubic B-spline with the points as its control points.
+///
+/// See [`b_spline`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::curves::antialiased_b_spline;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let points = [(10.0, 300.0), (100.5, 120.0), (200.0, 250.0), (390.0, 200.0)];
+/// antialiased_b_spline(&mut image, points, 2.0, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_b_spline`](crate::Draw::antialiased_b_spline)
+///
+pub fn antialiased_b_spline<P, T, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    width: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    It: IntoIterator<Item = P>,
+{
+    let curves = b_spline_curves(&collect(points));
+    stroke(image, &curves, width, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{b_spline_curves, cardinal_curves, pixels};
+    use crate::Pt;
+
+    const POINTS: [(i32, i32); 6] = [
+        (10, 300),
+        (80, 120),
+        (160, 250),
+        (230, 60),
+        (300, 330),
+        (390, 200),
+    ];
+
+    fn points() -> Vec<Pt<f64>> {
+        POINTS
+            .iter()
+            .map(|&p| Pt::<f64>::from(Pt::from(p)))
+            .collect()
+    }
+
+    #[test]
+    fn splines() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        crate::lines::path(&mut image, POINTS, image::Rgba([200, 200, 200, 255]));
+        super::catmull_rom(&mut image, POINTS, 0.0, image::Rgba([255, 0, 0, 255]));
+        super::catmull_rom(&mut image, POINTS, 0.6, image::Rgba([0, 160, 0, 255]));
+        super::b_spline(&mut image, POINTS, image::Rgba([0, 0, 255, 255]));
+        image.save("images/splines.png")
+    }
+
+    #[test]
+    fn antialiased_splines() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        super::antialiased_catmull_rom(&mut image, POINTS, 0.0, 2.5, image::Rgba([255, 0, 0, 255]));
+        super::antialiased_b_spline(&mut image, POINTS, 2.5, image::Rgba([0, 0, 255, 255]));
+        image.save("images/antialiased_splines.png")
+    }
+
+    #[test]
+    fn catmull_rom_through_points() {
+        let curves = cardinal_curves(&points(), 0.0);
+        assert_eq!(curves.len(), POINTS.len() - 1);
+        for (curve, w) in curves.iter().zip(points().windows(2)) {
+            assert_eq!(curve[0], w[0]);
+            assert_eq!(curve[3], w[1]);
+        }
+        // Curves meet smoothly
+        for w in curves.windows(2) {
+            let (a, b) = (w[0][3] - w[0][2], w[1][1] - w[1][0]);
+            assert!((a.x() - b.x()).abs() < 1e-9 && (a.y() - b.y()).abs() < 1e-9);
+        }
+
+        // Full tension draws straight lines
+        for curve in cardinal_curves(&points(), 1.0) {
+            assert_eq!(curve[0], curve[1]);
+            assert_eq!(curve[2], curve[3]);
+        }
+    }
+
+    #[test]
+    fn b_spline_ends() {
+        let pts = points();
+        let curves = b_spline_curves(&pts);
+        assert_eq!(curves.len(), pts.len() + 1);
+        assert_eq!(curves[0][0], pts[0]);
+        assert_eq!(curves[curves.len() - 1][3], pts[pts.len() - 1]);
+        for w in curves.windows(2) {
+            assert!((w[0][3].x() - w[1][0].x()).abs() < 1e-9);
+            assert!((w[0][3].y() - w[1][0].y()).abs() < 1e-9);
+        }
+        assert!(b_spline_curves(&[]).is_empty());
+    }
+
+    #[test]
+    fn spline_pixels_connected() {
+        let dist = |a: Pt<i32>, b: Pt<i32>| (a.x() - b.x()).abs().max((a.y() - b.y()).abs());
+
+        for curves in [
+            cardinal_curves(&points(), 0.0),
+            cardinal_curves(&points(), 0.5),
+            b_spline_curves(&points()),
+        ] {
+            let pts = pixels(&curves);
+            for w in pts.windows(2) {
+                assert_eq!(dist(w[0], w[1]), 1, "{w:?}");
+            }
+            for w in pts.windows(3) {
+                assert!(dist(w[0], w[2]) > 1, "{w:?}");
+            }
+        }
+    }
+}
@@ -10,6 +10,7 @@
 #![allow(clippy::must_use_candidate)]
 
 use crate::conics;
+use crate::curves;
 use crate::lines;
 use crate::ops;
 use crate::shapes;
@@ -104,17 +105,74 @@ where
     /// draw.dashed_line((10, 10), (120, 180), 3, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`lines::dashed_line`]
+    /// See [`lines::dashed_line`] and [`lines::DashPattern`]
     ///
-    pub fn dashed_line<P, T>(self, a: P, b: P, dash_width: u16, color: I::Pixel) -> Self
+    pub fn dashed_line<P, T, D>(self, a: P, b: P, pattern: D, color: I::Pixel) -> Self
     where
         P: Point<T>,
         T: Into<i32> + Copy,
+        D: Into<lines::DashPattern>,
     {
         let a = Pt::new(a.x().into(), a.y().into());
         let b = Pt::new(b.x().into(), b.y().into());
 
-        lines::dashed_line(self.image, a, b, dash_width, color);
+        lines::dashed_line(self.image, a, b, pattern, color);
+        self
+    }
+
+    /// Draws a line with a thickness in pixels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a 4px thick line between the two points
+    /// draw.thick_line((10, 10), (120, 180), 4, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::thick_line`]
+    ///
+    pub fn thick_line<P, T>(self, a: P, b: P, width: u16, color: I::Pixel) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        lines::thick_line(self.image, a, b, width, color);
+        self
+    }
+
+    /// Draws a line with a thickness in pixels and a cap at both ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::lines::LineCap;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a 7px thick line with rounded ends between the two points
+    /// draw.thick_line_capped((10, 10), (120, 180), 7, LineCap::Round, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::thick_line_capped`]
+    ///
+    pub fn thick_line_capped<P, T>(
+        self,
+        a: P,
+        b: P,
+        width: u16,
+        cap: lines::LineCap,
+        color: I::Pixel,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        lines::thick_line_capped(self.image, a, b, width, cap, color);
         self
     }
 
@@ -145,29 +203,37 @@ where
         self
     }
 
-    /// Draws a rectangle.
+    /// Draws a dashed line from each point to the next.
+    ///
+    /// Does not connect the start and end points.  The dashes carry on from one
+    /// line to the next.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
+    /// use freehand::lines::DashPattern;
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// draw.rectangle((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// // Draws a dash-dot line between each of the points
+    /// let points = [(10, 10), (120, 180), (300, 20)];
+    /// draw.dashed_path(points, DashPattern::new([8, 3, 2, 3], 0), Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`shapes::rectangle`]
+    /// See [`lines::dashed_path`]
     ///
-    pub fn rectangle<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    pub fn dashed_path<P, It, D>(self, points: It, pattern: D, color: I::Pixel) -> Self
     where
-        P: Point<u32>,
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+        D: Into<lines::DashPattern>,
     {
-        shapes::rectangle(self.image, pt, height, width, color);
+        lines::dashed_path(self.image, points, pattern, color);
         self
     }
 
-    /// Draws a filled rectangle
+    /// Draws a quadratic Bézier curve.
     ///
     /// # Example
     ///
@@ -176,20 +242,22 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// draw.rectangle_filled((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
+    /// // Draws a curve from (10, 380) to (390, 380) that bends towards (200, 20)
+    /// draw.quadratic_bezier((10, 380), (200, 20), (390, 380), Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`shapes::rectangle_filled`]
+    /// See [`curves::quadratic_bezier`]
     ///
-    pub fn rectangle_filled<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
+    pub fn quadratic_bezier<P, T>(self, a: P, c: P, b: P, color: I::Pixel) -> Self
     where
-        P: Point<u32>,
+        P: Point<T>,
+        T: Into<f64> + Copy,
     {
-        shapes::rectangle_filled(self.image, pt, height, width, color);
+        curves::quadratic_bezier(self.image, a, c, b, color);
         self
     }
 
-    /// Draws a circular arc.
+    /// Draws a cubic Bézier curve.
     ///
     /// # Example
     ///
@@ -198,30 +266,22 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red arc from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// // Draws an S shaped curve from (20, 200) to (380, 200)
+    /// draw.cubic_bezier((20, 200), (150, 20), (250, 380), (380, 200), Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::arc`]
+    /// See [`curves::cubic_bezier`]
     ///
-    pub fn arc<A, C, T>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: T,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
+    pub fn cubic_bezier<P, T>(self, a: P, c1: P, c2: P, b: P, color: I::Pixel) -> Self
     where
-        A: Angle,
-        C: Point<T>,
-        T: Into<i32> + Copy,
+        P: Point<T>,
+        T: Into<f64> + Copy,
     {
-        conics::arc(self.image, start_angle, end_angle, radius, center, color);
+        curves::cubic_bezier(self.image, a, c1, c2, b, color);
         self
     }
 
-    /// Draws a circle.
+    /// Draws a smooth curve through each of the points.
     ///
     /// # Example
     ///
@@ -230,22 +290,24 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red circle with a radius of 180 pixels from the image center.
-    /// draw.circle(180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// // Draws a Catmull-Rom spline through the points
+    /// let points = [(10, 300), (100, 120), (200, 250), (300, 50), (390, 200)];
+    /// draw.catmull_rom(points, 0.0, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::circle`]
+    /// See [`curves::catmull_rom`]
     ///
-    pub fn circle<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    pub fn catmull_rom<P, It>(self, points: It, tension: f32, color: I::Pixel) -> Self
     where
-        C: Point<T>,
-        T: Into<i32> + Copy,
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
     {
-        conics::circle(self.image, radius, center, color);
+        curves::catmull_rom(self.image, points, tension, color);
         self
     }
 
-    /// Draws a filled pie slice.
+    /// Draws a smooth curve using a B-spline with the points as its control
+    /// points.
     ///
     /// # Example
     ///
@@ -254,30 +316,22 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a pie slice from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.pie_slice_filled(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// let points = [(10, 300), (100, 120), (200, 250), (300, 50), (390, 200)];
+    /// draw.b_spline(points, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::pie_slice_filled`]
+    /// See [`curves::b_spline`]
     ///
-    pub fn pie_slice_filled<A, C>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: i32,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
+    pub fn b_spline<P, It>(self, points: It, color: I::Pixel) -> Self
     where
-        A: Angle,
-        C: Point<i32>,
-        I: GenericImage,
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
     {
-        conics::pie_slice_filled(self.image, start_angle, end_angle, radius, center, color);
+        curves::b_spline(self.image, points, color);
         self
     }
 
-    /// Draws a thick arc.
+    /// Draws a rectangle.
     ///
     /// # Example
     ///
@@ -286,38 +340,20 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws an arc, with a thickness of 3, from 0° to 55°, with a radius of 180 pixels from the image center.
-    /// draw.thick_arc(0, 55, 180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.rectangle((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::thick_arc`]
+    /// See [`shapes::rectangle`]
     ///
-    pub fn thick_arc<A, C>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: i32,
-        thickness: i16,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
+    pub fn rectangle<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
     where
-        A: Angle,
-        C: Point<i32>,
+        P: Point<u32>,
     {
-        conics::thick_arc(
-            self.image,
-            start_angle,
-            end_angle,
-            radius,
-            thickness,
-            center,
-            color,
-        );
+        shapes::rectangle(self.image, pt, height, width, color);
         self
     }
 
-    /// Draws a thick circle.
+    /// Draws a filled rectangle
     ///
     /// # Example
     ///
@@ -326,21 +362,21 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a circle with a thickness of 3 and a radius of 180 pixels from the image center.
-    /// draw.thick_circle(180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.rectangle_filled((10, 10), 50, 50, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::thick_circle`]
+    /// See [`shapes::rectangle_filled`]
     ///
-    pub fn thick_circle<C>(self, radius: i32, thickness: i16, center: C, color: I::Pixel) -> Self
+    pub fn rectangle_filled<P>(self, pt: P, height: u32, width: u32, color: I::Pixel) -> Self
     where
-        C: Point<i32>,
+        P: Point<u32>,
     {
-        conics::thick_circle(self.image, radius, thickness, center, color);
+        shapes::rectangle_filled(self.image, pt, height, width, color);
         self
     }
 
-    /// Draws an annulus (a filled donut)
+    /// Draws the outline of a polygon, connecting the last point back to the
+    /// first.
     ///
     /// # Example
     ///
@@ -349,75 +385,46 @@ where
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws an annulus from 0° to 55°, with an inner radius of 120 and outer radius of 180 pixels from the image center.
-    /// draw.annulus(0, 55, 120, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// draw.polygon([(200, 20), (370, 350), (30, 350)], Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::annulus`]
+    /// See [`shapes::polygon`]
     ///
-    pub fn annulus<A, C>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        inner_radius: i32,
-        outer_radius: i32,
-        center: C,
-        color: I::Pixel,
-    ) -> Self
+    pub fn polygon<P, It>(self, points: It, color: I::Pixel) -> Self
     where
-        A: Angle,
-        C: Point<i32>,
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
     {
-        conics::annulus(
-            self.image,
-            start_angle,
-            end_angle,
-            inner_radius,
-            outer_radius,
-            center,
-            color,
-        );
+        shapes::polygon(self.image, points, color);
         self
     }
-}
 
-/// Methods for working with [`image::RgbaImage`]s.
-///
-/// [`image::RgbaImage`]: https://docs.rs/image/latest/image/type.RgbaImage.html
-impl<'i> Draw<'i, RgbaImage> {
-    /// Draws an antialiased arc.
+    /// Fills a polygon using the given fill rule.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::FillRule;
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // draws an anti-aliased arc from 0° to 55° with a radius of 180 pixels from the image center.
-    /// draw.antialiased_arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+    /// draw.polygon_filled(star, FillRule::NonZero, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`conics::antialiased_arc`]
+    /// See [`shapes::polygon_filled`]
     ///
-    pub fn antialiased_arc<A, C, T>(
-        self,
-        start_angle: A,
-        end_angle: A,
-        radius: T,
-        center: C,
-        color: Rgba<u8>,
-    ) -> Self
+    pub fn polygon_filled<P, It>(self, points: It, rule: shapes::FillRule, color: I::Pixel) -> Self
     where
-        A: Angle,
-        C: Point<T>,
-        T: Into<f64> + Copy,
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
     {
-        conics::antialiased_arc(self.image, start_angle, end_angle, radius, center, color);
+        shapes::polygon_filled(self.image, points, rule, color);
         self
     }
 
-    /// Draws a dashed line with a specified opacity.
+    /// Draws the outline of a triangle.
     ///
     /// # Example
     ///
@@ -426,28 +433,20 @@ impl<'i> Draw<'i, RgbaImage> {
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red line with a 3px dash and 50% opacity.
-    /// draw.dashed_line_alpha((0, 10), (200, 200), 5u8, 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.triangle((200, 20), (370, 350), (30, 300), Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`lines::dashed_line_alpha`]
-    pub fn dashed_line_alpha<P, W>(
-        self,
-        a: P,
-        b: P,
-        dash_width: W,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
+    /// See [`shapes::triangle`]
+    ///
+    pub fn triangle<P>(self, a: P, b: P, c: P, color: I::Pixel) -> Self
     where
         P: Point<i32>,
-        W: Into<u16>,
     {
-        lines::dashed_line_alpha(self.image, a, b, dash_width, opacity, color);
+        shapes::triangle(self.image, a, b, c, color);
         self
     }
 
-    /// Draws a line with a specified opacity.
+    /// Fills a triangle.
     ///
     /// # Example
     ///
@@ -456,101 +455,1711 @@ impl<'i> Draw<'i, RgbaImage> {
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red line with 50% opacity.
-    /// draw.line_alpha((0, 10), (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// draw.triangle_filled((200, 20), (370, 350), (30, 300), Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`lines::line_alpha`]
+    /// See [`shapes::triangle_filled`]
     ///
-    pub fn line_alpha<P>(self, a: P, b: P, opacity: f32, color: Rgba<u8>) -> Self
+    pub fn triangle_filled<P>(self, a: P, b: P, c: P, color: I::Pixel) -> Self
     where
         P: Point<i32>,
     {
-        lines::line_alpha(self.image, a, b, opacity, color);
+        shapes::triangle_filled(self.image, a, b, c, color);
         self
     }
 
-    /// Draws a thick anti-aliased line.
+    /// Draws the outline of a path with 1px lines.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red anti-aliased line with a width of 1.5
-    /// draw.antialiased_line((0, 10), (200, 200), 1.5, Rgba([255, 0, 0, 255]));
+    /// // Draws a triangle with a curved bottom
+    /// let path = Path::new()
+    ///     .move_to((200, 50))
+    ///     .line_to((350, 300))
+    ///     .quad_to((200, 380), (50, 300))
+    ///     .close();
+    /// draw.stroke_path(&path, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`lines::antialiased_line`]
+    /// See [`shapes::Path::stroke`]
     ///
-    pub fn antialiased_line<P, T>(self, a: P, b: P, width: f32, color: Rgba<u8>) -> Self
-    where
-        P: Point<T>,
-        T: Into<i32> + Copy,
-    {
-        lines::antialiased_line(self.image, a, b, width, color);
+    pub fn stroke_path(self, path: &shapes::Path, color: I::Pixel) -> Self {
+        path.stroke(self.image, color);
         self
     }
 
-    /// Draws a rectangle with the specified opacity.
+    /// Draws the outline of a path with lines that are `width` pixels thick.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a red rectangle with 50% opacity.
-    /// draw.rectangle_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// // Draws a triangle with a curved bottom with 5px thick lines
+    /// let path = Path::new()
+    ///     .move_to((200, 50))
+    ///     .line_to((350, 300))
+    ///     .quad_to((200, 380), (50, 300))
+    ///     .close();
+    /// draw.thick_stroke_path(&path, 5, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`shapes::rectangle_alpha`]
+    /// See [`shapes::Path::thick_stroke`]
     ///
-    pub fn rectangle_alpha<P>(
-        self,
-        pt: P,
-        height: u32,
-        width: u32,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
-    where
-        P: Point<u32>,
-    {
-        shapes::rectangle_alpha(self.image, pt, height, width, opacity, color);
+    pub fn thick_stroke_path(self, path: &shapes::Path, width: u16, color: I::Pixel) -> Self {
+        path.thick_stroke(self.image, width, color);
         self
     }
 
-    /// Draws a filled rectangle with the specified opacity.
+    /// Fills the inside of a path.
     ///
     /// # Example
     ///
     /// ```
     /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
     /// # let mut image = RgbaImage::new(400, 400);
     ///
     /// let draw = freehand::new(&mut image);
-    /// // Draws a filled red rectangle with 50% opacity.
-    /// draw.rectangle_filled_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// // Fills a triangle with a curved bottom
+    /// let path = Path::new()
+    ///     .move_to((200, 50))
+    ///     .line_to((350, 300))
+    ///     .quad_to((200, 380), (50, 300))
+    ///     .close();
+    /// draw.fill_path(&path, Rgba([255, 0, 0, 255]));
     /// ```
     ///
-    /// See [`shapes::rectangle_filled_alpha`]
+    /// See [`shapes::Path::fill`]
     ///
-    pub fn rectangle_filled_alpha<P>(
-        self,
-        pt: P,
-        height: u32,
-        width: u32,
-        opacity: f32,
-        color: Rgba<u8>,
-    ) -> Self
-    where
-        P: Point<u32>,
-    {
-        shapes::rectangle_filled_alpha(self.image, pt, height, width, opacity, color);
+    pub fn fill_path(self, path: &shapes::Path, color: I::Pixel) -> Self {
+        path.fill(self.image, color);
+        self
+    }
+
+    /// Draws a circular arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red arc from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::arc`]
+    ///
+    pub fn arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::arc(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws a dashed circular arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red arc from 0° to 180° with 8px dashes
+    /// draw.dashed_arc(0, 180, 180, (200, 200), 8, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::dashed_arc`]
+    ///
+    pub fn dashed_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        dash_width: u16,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::dashed_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            dash_width,
+            color,
+        );
+        self
+    }
+
+    /// Draws a circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red circle with a radius of 180 pixels from the image center.
+    /// draw.circle(180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle`]
+    ///
+    pub fn circle<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::circle(self.image, radius, center, color);
+        self
+    }
+
+    /// Draws a dashed circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red circle with 6px dashes
+    /// draw.dashed_circle(180, (200, 200), 6, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::dashed_circle`]
+    ///
+    pub fn dashed_circle<C, T>(self, radius: T, center: C, dash_width: u16, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::dashed_circle(self.image, radius, center, dash_width, color);
+        self
+    }
+
+    /// Draws a filled circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a filled red circle with a radius of 5.5 pixels
+    /// draw.circle_filled(5.5, (200.5, 200.5), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle_filled`]
+    ///
+    pub fn circle_filled<C, T>(self, radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::circle_filled(self.image, radius, center, color);
+        self
+    }
+
+    /// Draws an axis-aligned ellipse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red ellipse 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.ellipse(180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::ellipse`]
+    ///
+    pub fn ellipse<C, T>(self, x_radius: T, y_radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::ellipse(self.image, x_radius, y_radius, center, color);
+        self
+    }
+
+    /// Draws an ellipse rotated counter-clockwise around its center.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red ellipse 180 pixels wide and 120 pixels tall, tilted by 30°.
+    /// draw.rotated_ellipse(180, 120, 30, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::rotated_ellipse`]
+    ///
+    pub fn rotated_ellipse<A, C, T>(
+        self,
+        x_radius: T,
+        y_radius: T,
+        rotation: A,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::rotated_ellipse(self.image, x_radius, y_radius, rotation, center, color);
+        self
+    }
+
+    /// Draws an elliptical arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red elliptical arc from 0° to 55°, 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.ellipse_arc(0, 55, 180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::ellipse_arc`]
+    ///
+    pub fn ellipse_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        x_radius: T,
+        y_radius: T,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::ellipse_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            x_radius,
+            y_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws a filled axis-aligned ellipse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red filled ellipse 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.ellipse_filled(180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::ellipse_filled`]
+    ///
+    pub fn ellipse_filled<C, T>(self, x_radius: T, y_radius: T, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::ellipse_filled(self.image, x_radius, y_radius, center, color);
+        self
+    }
+
+    /// Draws a filled elliptical pie slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a pie slice from 0° to 55°, 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.ellipse_pie_slice_filled(0, 55, 180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::ellipse_pie_slice_filled`]
+    ///
+    pub fn ellipse_pie_slice_filled<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        x_radius: T,
+        y_radius: T,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<i32> + Copy,
+    {
+        conics::ellipse_pie_slice_filled(
+            self.image,
+            start_angle,
+            end_angle,
+            x_radius,
+            y_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws a filled pie slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a pie slice from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.pie_slice_filled(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::pie_slice_filled`]
+    ///
+    pub fn pie_slice_filled<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+        I: GenericImage,
+    {
+        conics::pie_slice_filled(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws a thick arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an arc, with a thickness of 3, from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.thick_arc(0, 55, 180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_arc`]
+    ///
+    pub fn thick_arc<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        thickness: i16,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::thick_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            thickness,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws a thick circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a circle with a thickness of 3 and a radius of 180 pixels from the image center.
+    /// draw.thick_circle(180, 3, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_circle`]
+    ///
+    pub fn thick_circle<C>(self, radius: i32, thickness: i16, center: C, color: I::Pixel) -> Self
+    where
+        C: Point<i32>,
+    {
+        conics::thick_circle(self.image, radius, thickness, center, color);
+        self
+    }
+
+    /// Draws an annulus (a filled donut)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an annulus from 0° to 55°, with an inner radius of 120 and outer radius of 180 pixels from the image center.
+    /// draw.annulus(0, 55, 120, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::annulus`]
+    ///
+    pub fn annulus<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radius: T,
+        outer_radius: T,
+        center: C,
+        color: I::Pixel,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::annulus(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+}
+
+/// Methods for working with [`image::RgbaImage`]s.
+///
+/// [`image::RgbaImage`]: https://docs.rs/image/latest/image/type.RgbaImage.html
+impl Draw<'_, RgbaImage> {
+    /// Draws an antialiased arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased arc from 0° to 55° with a radius of 180 pixels from the image center.
+    /// draw.antialiased_arc(0, 55, 180, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_arc`]
+    ///
+    pub fn antialiased_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_arc(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws a dashed antialiased arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an antialiased arc from 0° to 180° with 8px dashes
+    /// draw.antialiased_dashed_arc(0, 180, 180, (200, 200), 8, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_dashed_arc`]
+    ///
+    pub fn antialiased_dashed_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        dash_width: u16,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_dashed_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            dash_width,
+            color,
+        );
+        self
+    }
+
+    /// Draws an antialiased filled circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased filled circle with a radius of 5.5 pixels
+    /// draw.antialiased_circle_filled(5.5, (200.5, 200.5), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_circle_filled`]
+    ///
+    pub fn antialiased_circle_filled<C, T>(self, radius: T, center: C, color: Rgba<u8>) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_circle_filled(self.image, radius, center, color);
+        self
+    }
+
+    /// Draws an antialiased annulus (a filled donut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased annulus from 0° to 55°, with an inner radius of 120 and outer radius of 180 pixels from the image center.
+    /// draw.antialiased_annulus(0, 55, 120.0, 180.0, (200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_annulus`]
+    ///
+    pub fn antialiased_annulus<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radius: T,
+        outer_radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_annulus(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws an antialiased thick arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased arc, with a thickness of 3, from 0° to 55°, with a radius of 180 pixels from the image center.
+    /// draw.antialiased_thick_arc(0, 55, 180.0, 3.0, (200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_thick_arc`]
+    ///
+    pub fn antialiased_thick_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        thickness: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_thick_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            thickness,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws an antialiased pie slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased pie slice from 0° to 55° with a radius of 180 pixels from the image center.
+    /// draw.antialiased_pie_slice(0, 55, 180.0, (200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_pie_slice`]
+    ///
+    pub fn antialiased_pie_slice<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_pie_slice(self.image, start_angle, end_angle, radius, center, color);
+        self
+    }
+
+    /// Draws an antialiased thick circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased circle with a thickness of 3 and a radius of 180 pixels from the image center.
+    /// draw.antialiased_thick_circle(180.0, 3.0, (200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_thick_circle`]
+    ///
+    pub fn antialiased_thick_circle<C, T>(
+        self,
+        radius: T,
+        thickness: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_thick_circle(self.image, radius, thickness, center, color);
+        self
+    }
+
+    /// Draws an antialiased ellipse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased ellipse 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.antialiased_ellipse(180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_ellipse`]
+    ///
+    pub fn antialiased_ellipse<C, T>(
+        self,
+        x_radius: T,
+        y_radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_ellipse(self.image, x_radius, y_radius, center, color);
+        self
+    }
+
+    /// Draws an antialiased ellipse rotated counter-clockwise around its center.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased ellipse 180 pixels wide and 120 pixels tall, tilted by 30°.
+    /// draw.antialiased_rotated_ellipse(180, 120, 30, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_rotated_ellipse`]
+    ///
+    pub fn antialiased_rotated_ellipse<A, C, T>(
+        self,
+        x_radius: T,
+        y_radius: T,
+        rotation: A,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_rotated_ellipse(
+            self.image, x_radius, y_radius, rotation, center, color,
+        );
+        self
+    }
+
+    /// Draws an antialiased elliptical arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // draws an anti-aliased elliptical arc from 0° to 55°, 180 pixels wide and 120 pixels tall from the image center.
+    /// draw.antialiased_ellipse_arc(0, 55, 180, 120, (200, 200), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::antialiased_ellipse_arc`]
+    ///
+    pub fn antialiased_ellipse_arc<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        x_radius: T,
+        y_radius: T,
+        center: C,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::antialiased_ellipse_arc(
+            self.image,
+            start_angle,
+            end_angle,
+            x_radius,
+            y_radius,
+            center,
+            color,
+        );
+        self
+    }
+
+    /// Draws a dashed line with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red line with a 3px dash and 50% opacity.
+    /// draw.dashed_line_alpha((0, 10), (200, 200), 5u8, 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::dashed_line_alpha`] and [`lines::DashPattern`]
+    pub fn dashed_line_alpha<P, D>(
+        self,
+        a: P,
+        b: P,
+        pattern: D,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<i32>,
+        D: Into<lines::DashPattern>,
+    {
+        lines::dashed_line_alpha(self.image, a, b, pattern, opacity, color);
+        self
+    }
+
+    /// Draws a line with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red line with 50% opacity.
+    /// draw.line_alpha((0, 10), (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::line_alpha`]
+    ///
+    pub fn line_alpha<P>(self, a: P, b: P, opacity: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<i32>,
+    {
+        lines::line_alpha(self.image, a, b, opacity, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased line with a width of 1.5
+    /// draw.antialiased_line((0, 10), (200, 200), 1.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::antialiased_line`]
+    ///
+    pub fn antialiased_line<P, T>(self, a: P, b: P, width: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        lines::antialiased_line(self.image, a, b, width, color);
+        self
+    }
+
+    /// Draws a 1px wide anti-aliased line using Xiaolin Wu's algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::Pt;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased line starting between two pixels
+    /// draw.wu_line(Pt::new(10.5, 10.0), Pt::new(200.0, 200.0), Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`lines::wu_line`]
+    ///
+    pub fn wu_line<P, T>(self, a: P, b: P, color: Rgba<u8>) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        lines::wu_line(self.image, a, b, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased line with a cap at both ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::lines::LineCap;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased line with square ends and a width of 3.5
+    /// draw.antialiased_line_capped(
+    ///     (10.5, 10.0),
+    ///     (200.0, 200.0),
+    ///     3.5,
+    ///     LineCap::Square,
+    ///     Rgba([255, 0, 0, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`lines::antialiased_line_capped`]
+    ///
+    pub fn antialiased_line_capped<P, T>(
+        self,
+        a: P,
+        b: P,
+        width: f32,
+        cap: lines::LineCap,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        lines::antialiased_line_capped(self.image, a, b, width, cap, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased dashed line with a cap at both ends of each
+    /// dash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::lines::{DashPattern, LineCap};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red dotted line with round dots 10px apart
+    /// draw.antialiased_dashed_line(
+    ///     (10, 10),
+    ///     (200, 200),
+    ///     4.0,
+    ///     DashPattern::new([0, 10], 0),
+    ///     LineCap::Round,
+    ///     Rgba([255, 0, 0, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`lines::antialiased_dashed_line`]
+    ///
+    pub fn antialiased_dashed_line<P, T, D>(
+        self,
+        a: P,
+        b: P,
+        width: f32,
+        pattern: D,
+        cap: lines::LineCap,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+        D: Into<lines::DashPattern>,
+    {
+        lines::antialiased_dashed_line(self.image, a, b, width, pattern, cap, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased line through each of the points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::lines::{LineCap, LineJoin};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a closed red triangle with rounded corners and a width of 5
+    /// draw.polyline_stroke(
+    ///     [(50, 350), (200, 50), (350, 350)],
+    ///     5.0,
+    ///     LineJoin::Round,
+    ///     LineCap::Butt,
+    ///     true,
+    ///     Rgba([255, 0, 0, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`lines::polyline_stroke`]
+    ///
+    pub fn polyline_stroke<P, T, It>(
+        self,
+        points: It,
+        width: f32,
+        join: lines::LineJoin,
+        cap: lines::LineCap,
+        closed: bool,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+        It: IntoIterator<Item = P>,
+    {
+        lines::polyline_stroke(self.image, points, width, join, cap, closed, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased quadratic Bézier curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased curve with a width of 2.5
+    /// draw.antialiased_quadratic_bezier(
+    ///     (10.0, 380.0),
+    ///     (200.0, 20.5),
+    ///     (390.0, 380.0),
+    ///     2.5,
+    ///     Rgba([255, 0, 0, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`curves::antialiased_quadratic_bezier`]
+    ///
+    pub fn antialiased_quadratic_bezier<P, T>(
+        self,
+        a: P,
+        c: P,
+        b: P,
+        width: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        curves::antialiased_quadratic_bezier(self.image, a, c, b, width, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased cubic Bézier curve.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased S shaped curve with a width of 3
+    /// draw.antialiased_cubic_bezier(
+    ///     (20.0, 200.0),
+    ///     (150.0, 20.0),
+    ///     (250.0, 380.0),
+    ///     (380.0, 200.0),
+    ///     3.0,
+    ///     Rgba([255, 0, 0, 255]),
+    /// );
+    /// ```
+    ///
+    /// See [`curves::antialiased_cubic_bezier`]
+    ///
+    pub fn antialiased_cubic_bezier<P, T>(
+        self,
+        a: P,
+        c1: P,
+        c2: P,
+        b: P,
+        width: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        curves::antialiased_cubic_bezier(self.image, a, c1, c2, b, width, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased smooth curve through each of the points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased Catmull-Rom spline with a width of 2
+    /// let points = [(10.0, 300.0), (100.5, 120.0), (200.0, 250.0), (390.0, 200.0)];
+    /// draw.antialiased_catmull_rom(points, 0.0, 2.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::antialiased_catmull_rom`]
+    ///
+    pub fn antialiased_catmull_rom<P, T, It>(
+        self,
+        points: It,
+        tension: f32,
+        width: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+        It: IntoIterator<Item = P>,
+    {
+        curves::antialiased_catmull_rom(self.image, points, tension, width, color);
+        self
+    }
+
+    /// Draws a thick anti-aliased smooth curve using a B-spline with the
+    /// points as its control points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red anti-aliased B-spline with a width of 2
+    /// let points = [(10.0, 300.0), (100.5, 120.0), (200.0, 250.0), (390.0, 200.0)];
+    /// draw.antialiased_b_spline(points, 2.0, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`curves::antialiased_b_spline`]
+    ///
+    pub fn antialiased_b_spline<P, T, It>(self, points: It, width: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+        It: IntoIterator<Item = P>,
+    {
+        curves::antialiased_b_spline(self.image, points, width, color);
+        self
+    }
+
+    /// Draws the outline of a path with 1px anti-aliased lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a half circle
+    /// let path = Path::new().arc_to(0, 180, 150, (200, 200)).close();
+    /// draw.antialiased_stroke_path(&path, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::Path::antialiased_stroke`]
+    ///
+    pub fn antialiased_stroke_path(self, path: &shapes::Path, color: Rgba<u8>) -> Self {
+        path.antialiased_stroke(self.image, color);
+        self
+    }
+
+    /// Draws the outline of a path with thick anti-aliased lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a half circle with a width of 3.5
+    /// let path = Path::new().arc_to(0, 180, 150, (200, 200)).close();
+    /// draw.antialiased_thick_stroke_path(&path, 3.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::Path::antialiased_thick_stroke`]
+    ///
+    pub fn antialiased_thick_stroke_path(
+        self,
+        path: &shapes::Path,
+        width: f32,
+        color: Rgba<u8>,
+    ) -> Self {
+        path.antialiased_thick_stroke(self.image, width, color);
+        self
+    }
+
+    /// Draws a rectangle with the specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red rectangle with 50% opacity.
+    /// draw.rectangle_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rectangle_alpha`]
+    ///
+    pub fn rectangle_alpha<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<u32>,
+    {
+        shapes::rectangle_alpha(self.image, pt, height, width, opacity, color);
+        self
+    }
+
+    /// Draws a filled rectangle with the specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a filled red rectangle with 50% opacity.
+    /// draw.rectangle_filled_alpha((0, 10), 50, 50, 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::rectangle_filled_alpha`]
+    ///
+    pub fn rectangle_filled_alpha<P>(
+        self,
+        pt: P,
+        height: u32,
+        width: u32,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<u32>,
+    {
+        shapes::rectangle_filled_alpha(self.image, pt, height, width, opacity, color);
+        self
+    }
+
+    /// Draws the outline of a polygon with the specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red triangle with 50% opacity.
+    /// draw.polygon_alpha([(200, 20), (370, 350), (30, 350)], 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::polygon_alpha`]
+    ///
+    pub fn polygon_alpha<P, It>(self, points: It, opacity: f32, color: Rgba<u8>) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        shapes::polygon_alpha(self.image, points, opacity, color);
+        self
+    }
+
+    /// Fills a polygon with the specified opacity using the given fill rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::FillRule;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Fills a red star with 50% opacity, leaving the center empty.
+    /// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+    /// draw.polygon_filled_alpha(star, FillRule::EvenOdd, 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::polygon_filled_alpha`]
+    ///
+    pub fn polygon_filled_alpha<P, It>(
+        self,
+        points: It,
+        rule: shapes::FillRule,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<i32>,
+        It: IntoIterator<Item = P>,
+    {
+        shapes::polygon_filled_alpha(self.image, points, rule, opacity, color);
+        self
+    }
+
+    /// Fills an antialiased polygon using the given fill rule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::FillRule;
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let triangle = [(200.0, 20.5), (370.25, 350.0), (30.0, 320.0)];
+    /// draw.antialiased_polygon_filled(triangle, FillRule::NonZero, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::antialiased_polygon_filled`]
+    ///
+    pub fn antialiased_polygon_filled<P, T, It>(
+        self,
+        points: It,
+        rule: shapes::FillRule,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+        It: IntoIterator<Item = P>,
+    {
+        shapes::antialiased_polygon_filled(self.image, points, rule, color);
+        self
+    }
+
+    /// Fills an antialiased triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let (a, b, c) = ((200.0, 20.5), (370.25, 350.0), (30.0, 300.75));
+    /// draw.antialiased_triangle_filled(a, b, c, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`shapes::antialiased_triangle_filled`]
+    ///
+    pub fn antialiased_triangle_filled<P, T>(self, a: P, b: P, c: P, color: Rgba<u8>) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        shapes::antialiased_triangle_filled(self.image, a, b, c, color);
+        self
+    }
+
+    /// Fills a triangle, blending the colors of each vertex across it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// let colors = [Rgba([255, 0, 0, 255]), Rgba([0, 255, 0, 255]), Rgba([0, 0, 255, 255])];
+    /// draw.gouraud_triangle((200, 20), (370, 350), (30, 300), colors);
+    /// ```
+    ///
+    /// See [`shapes::gouraud_triangle`]
+    ///
+    pub fn gouraud_triangle<P>(self, a: P, b: P, c: P, colors: [Rgba<u8>; 3]) -> Self
+    where
+        P: Point<i32>,
+    {
+        shapes::gouraud_triangle(self.image, a, b, c, colors);
+        self
+    }
+
+    /// Draws a circular arc with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red arc from 0° to 55° with 50% opacity.
+    /// draw.arc_alpha(0, 55, 180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::arc_alpha`]
+    ///
+    pub fn arc_alpha<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: T,
+        center: C,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::arc_alpha(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            opacity,
+            color,
+        );
+        self
+    }
+
+    /// Draws a circle with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red circle with 50% opacity.
+    /// draw.circle_alpha(180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::circle_alpha`]
+    ///
+    pub fn circle_alpha<C, T>(self, radius: T, center: C, opacity: f32, color: Rgba<u8>) -> Self
+    where
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::circle_alpha(self.image, radius, center, opacity, color);
+        self
+    }
+
+    /// Draws an annulus with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red annulus from 0° to 55° with 50% opacity.
+    /// draw.annulus_alpha(0, 55, 150, 180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::annulus_alpha`]
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn annulus_alpha<A, C, T>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        inner_radius: T,
+        outer_radius: T,
+        center: C,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        conics::annulus_alpha(
+            self.image,
+            start_angle,
+            end_angle,
+            inner_radius,
+            outer_radius,
+            center,
+            opacity,
+            color,
+        );
+        self
+    }
+
+    /// Draws a thick arc with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws an arc, with a thickness of 3, from 0° to 55° with 50% opacity.
+    /// draw.thick_arc_alpha(0, 55, 180, 3, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_arc_alpha`]
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn thick_arc_alpha<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        thickness: i16,
+        center: C,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::thick_arc_alpha(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            thickness,
+            center,
+            opacity,
+            color,
+        );
+        self
+    }
+
+    /// Draws a pie slice with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a red pie slice from 0° to 55° with 50% opacity.
+    /// draw.pie_slice_filled_alpha(0, 55, 180, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::pie_slice_filled_alpha`]
+    ///
+    pub fn pie_slice_filled_alpha<A, C>(
+        self,
+        start_angle: A,
+        end_angle: A,
+        radius: i32,
+        center: C,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        A: Angle,
+        C: Point<i32>,
+    {
+        conics::pie_slice_filled_alpha(
+            self.image,
+            start_angle,
+            end_angle,
+            radius,
+            center,
+            opacity,
+            color,
+        );
+        self
+    }
+
+    /// Draws a thick circle with a specified opacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// # let mut image = RgbaImage::new(400, 400);
+    ///
+    /// let draw = freehand::new(&mut image);
+    /// // Draws a circle with a thickness of 3 and 50% opacity.
+    /// draw.thick_circle_alpha(180, 3, (200, 200), 0.5, Rgba([255, 0, 0, 255]));
+    /// ```
+    ///
+    /// See [`conics::thick_circle_alpha`]
+    ///
+    pub fn thick_circle_alpha<C>(
+        self,
+        radius: i32,
+        thickness: i16,
+        center: C,
+        opacity: f32,
+        color: Rgba<u8>,
+    ) -> Self
+    where
+        C: Point<i32>,
+    {
+        conics::thick_circle_alpha(self.image, radius, thickness, center, opacity, color);
         self
     }
 
@@ -623,7 +2232,7 @@ impl<'i> Draw<'i, RgbaImage> {
 ///
 /// let draw = freehand::new(&mut image);
 /// ```
-pub fn new<I>(image: &mut I) -> Draw<I>
+pub fn new<I>(image: &mut I) -> Draw<'_, I>
 where
     I: image::GenericImage,
 {
@@ -16,8 +16,9 @@
 //!
 //! Current features:
 //! - [line drawing functions](lines)
-//! - [circles, circular arcs, and annuli (filled donut shapes)](conics)
-//! - [shapes (currently only rectangles)][shapes]
+//! - [circles, filled circles, circular arcs, annuli (filled donut shapes), ellipses, elliptical arcs, and rotated ellipses, with antialiased variants](conics)
+//! - [quadratic and cubic Bézier curves, Catmull-Rom splines, and B-splines, with antialiased variants](curves)
+//! - [shapes: rectangles, triangles with per-vertex colors, polygons with even-odd and nonzero fills and antialiased variants, and paths made of lines, curves and arcs][shapes]
 //!
 //! # Cargo.toml
 //!
@@ -111,9 +112,9 @@
 macro_rules! check_img_i32 {
     ( $img:ident ) => {
         assert!(
-            $img.height() <= (std::i32::MAX as u32) && $img.width() <= (std::i32::MAX as u32),
+            i32::try_from($img.height()).is_ok() && i32::try_from($img.width()).is_ok(),
             "Image is too large, max size for height/width is {}.  size={:?}",
-            std::i32::MAX,
+            i32::MAX,
             $img.dimensions()
         );
     };
@@ -145,6 +146,7 @@ pub(crate) mod draw;
 pub(crate) mod translate;
 
 pub mod conics;
+pub mod curves;
 pub mod lines;
 pub mod ops;
 pub mod shapes;
@@ -167,21 +169,24 @@ const PI2: f64 = std::f64::consts::PI * 2.0;
 /// Range of a single quadrant in radians
 const QUAD: f64 = std::f64::consts::PI / 2.0;
 /// Tiny amount to subtract from an angle (in radians) to avoid different angles from appearing the same
-const TINY: f64 = std::f64::EPSILON * 10.0; // multiply by a number greater than 2.0 to avoid floating-point inaccuracy
+const TINY: f64 = f64::EPSILON * 10.0; // multiply by a number greater than 2.0 to avoid floating-point inaccuracy
 
 /// A simple helper function that draws `(x, y)` coordinates returned from an iterator.
+///
+/// Coordinates that are negative or outside of the image are skipped.
 pub fn draw_iter<I, P, It, T>(image: &mut I, iter: It, color: I::Pixel)
 where
     I: image::GenericImage,
     It: Iterator<Item = P>,
     P: crate::pt::Point<T>,
-    T: Into<u32> + Copy,
+    T: TryInto<u32> + Copy,
 {
     for p in iter {
         let (x, y) = p.tuple();
-        let (x, y) = (x.into(), y.into());
-        if x < image.width() && y < image.height() {
-            image.put_pixel(x, y, color);
+        if let (Ok(x), Ok(y)) = (x.try_into(), y.try_into()) {
+            if x < image.width() && y < image.height() {
+                image.put_pixel(x, y, color);
+            }
         }
     }
 }
@@ -194,10 +199,12 @@ fn rgba_array_index(img_width: u32, x: u32, y: u32) -> usize {
     (y * img_width + x) as usize * std::mem::size_of::<image::Rgba<u8>>()
 }
 
-/// Calculate the error for a point in a circle.  Assumes octant 7.
-fn calc_error(pt: Pt<f64>, r: i32) -> i32 {
-    ((pt.x().round() + 1.0).powi(2) + (pt.y().round() - 0.5).powi(2) - r.pow(2) as f64).round()
-        as i32
+/// Calculate the error for a point in a circle, relative to the center of the
+/// circle.  Assumes octant 7.
+///
+/// A positive error means the next point should move towards the center.
+fn calc_error(pt: Pt<f64>, r: f64) -> f64 {
+    (pt.x() + 1.0).powi(2) + pt.y().powi(2) - pt.y() - r.powi(2)
 }
 
 /// Calculate the slope of a line
@@ -1,5 +1,5 @@
 //! Horizontal, vertical, and diagonal lines with variations for solid, dashed,
-//! and alpha blended lines.
+//! thick, and alpha blended lines.
 //!
 //! ```
 //! # use image::{RgbaImage, Rgba};
@@ -10,15 +10,20 @@
 //! ```
 
 mod bres;
+mod dash;
 mod diagonal;
 mod horizontal;
 mod straight;
+mod stroke;
 mod thick;
 // mod thick;
 mod vertical;
+mod wu;
 
 pub use bres::LineIter;
 
+pub use dash::DashPattern;
+
 pub use diagonal::{
     diagonal_dashed_line, diagonal_dashed_line_alpha, diagonal_line, diagonal_line_alpha,
 };
@@ -31,6 +36,13 @@ pub use vertical::{
     vertical_dashed_line, vertical_dashed_line_alpha, vertical_line, vertical_line_alpha,
 };
 
-pub use straight::{dashed_line, dashed_line_alpha, line, line_alpha, path};
+pub use straight::{dashed_line, dashed_line_alpha, dashed_path, line, line_alpha, path};
+
+pub use stroke::{polyline_stroke, LineCap, LineJoin};
+
+pub use thick::{
+    antialiased_dashed_line, antialiased_line, antialiased_line_capped, thick_line,
+    thick_line_capped,
+};
 
-pub use thick::antialiased_line;
+pub use wu::{wu_line, WuLine};
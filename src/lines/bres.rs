@@ -79,6 +79,92 @@ impl LineIter {
         }
     }
 
+    /// Skips the points of the line that are outside of the rectangle from
+    /// `min` to `max` (inclusive), without stepping through them.
+    ///
+    /// The points that are left are exactly the points that would have been
+    /// found without clipping.  Returns the number of points skipped at the
+    /// start of the line, which is the index of the next point in the
+    /// unclipped line.  If no points are inside of the rectangle the iterator
+    /// will be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use freehand::lines::LineIter;
+    /// use freehand::Pt;
+    ///
+    /// let mut line = LineIter::new((-1_000_000, 0), (1_000_000, 400));
+    /// let skipped = line.clip((0, 0), (399, 399));
+    ///
+    /// let unclipped = LineIter::new((-1_000_000, 0), (1_000_000, 400));
+    /// let inside = unclipped.skip(skipped as usize).take_while(|pt| pt.x() < 400);
+    /// assert!(line.eq(inside));
+    /// ```
+    pub fn clip<P>(&mut self, min: P, max: P) -> u64
+    where
+        P: Point<i32>,
+    {
+        let (mut min, mut max) = (min.pt(), max.pt());
+        if self.steep {
+            min.swap();
+            max.swap();
+        }
+
+        let x = i64::from(self.pt.x());
+        let y = i64::from(self.pt.y());
+        let d = i64::from(self.d);
+        let dy = i64::from(self.dy);
+        let dx = i64::from(self.dx);
+        let y_step = i64::from(self.y_step);
+
+        // Steps along the major axis that stay inside of the rectangle
+        let mut lo = (i64::from(min.x()) - x).max(0);
+        let mut hi = (i64::from(max.x()) - x).min(i64::from(self.end.x()) - x);
+
+        // Number of minor axis steps after k major axis steps
+        let minor = |k: i64| {
+            if dx == 0 {
+                0
+            } else {
+                (d + k * dy - dx + 2 * dx - 1).div_euclid(2 * dx).max(0)
+            }
+        };
+
+        // Minor axis steps that stay inside of the rectangle
+        let (m_lo, m_hi) = match y_step {
+            1 => (i64::from(min.y()) - y, i64::from(max.y()) - y),
+            -1 => (y - i64::from(max.y()), y - i64::from(min.y())),
+            _ if (i64::from(min.y())..=i64::from(max.y())).contains(&y) => (0, 0),
+            _ => (1, 0),
+        };
+
+        if m_lo > m_hi || m_hi < 0 {
+            hi = lo - 1;
+        } else if dy > 0 {
+            // The first step with at least m_lo minor steps
+            if m_lo > 0 {
+                lo = lo.max((2 * dx * (m_lo - 1) + dx - d).div_euclid(dy) + 1);
+            }
+            // The last step with at most m_hi minor steps
+            hi = hi.min((2 * dx * m_hi + dx - d).div_euclid(dy));
+        }
+
+        let skipped = lo.max(0);
+        if lo > hi {
+            // Nothing left to iterate over
+            self.end = Pt::new(self.pt.x() - 1, self.pt.y());
+            return skipped as u64;
+        }
+
+        let m = minor(lo);
+        self.pt = Pt::new((x + lo) as i32, (y + y_step * m) as i32);
+        self.d = (d + lo * dy - 2 * dx * m) as i32;
+        self.end = Pt::new((x + hi) as i32, (y + y_step * minor(hi)) as i32);
+
+        skipped as u64
+    }
+
     /// Returns the current position in the line.
     #[must_use]
     pub fn pt(&self) -> Pt<i32> {
@@ -162,3 +248,37 @@ impl Iterator for LineIter {
         Some(pt)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::LineIter;
+    use crate::Pt;
+
+    #[test]
+    fn clip() {
+        let (min, max) = (Pt::new(0, 0), Pt::new(9, 7));
+        let inside = |pt: &Pt<i32>| (0..=9).contains(&pt.x()) && (0..=7).contains(&pt.y());
+        let coords = [-13, -4, -1, 0, 3, 7, 8, 9, 10, 16, 22];
+
+        for &ax in &coords {
+            for &ay in &coords {
+                for &bx in &coords {
+                    for &by in &coords {
+                        let (a, b) = (Pt::new(ax, ay), Pt::new(bx, by));
+                        let all: Vec<_> = LineIter::new(a, b).collect();
+                        let expected: Vec<_> = all.iter().copied().filter(inside).collect();
+
+                        let mut line = LineIter::new(a, b);
+                        let skipped = line.clip(min, max);
+                        let clipped: Vec<_> = line.collect();
+
+                        assert_eq!(clipped, expected, "{a:?} to {b:?}");
+                        if let Some(first) = expected.first() {
+                            assert_eq!(all[skipped as usize], *first, "{a:?} to {b:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
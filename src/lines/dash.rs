@@ -0,0 +1,257 @@
+/// A repeating pattern of dashes and gaps for dashed lines.
+///
+/// The lengths are in pixels along the line and alternate between dashes and
+/// gaps, starting with a dash.  A pattern with an odd number of lengths is
+/// repeated once so that every length is used as both a dash and a gap, like
+/// the SVG `stroke-dasharray` property.  The offset is how far into the pattern
+/// the line starts.
+///
+/// The pattern is always measured from the first point given to a line
+/// function (`a`, or `pt` for horizontal and vertical lines), whichever way
+/// the line goes and whether or not that point is inside of the image.
+/// Swapping the end points of a line measures the pattern from the other end.
+///
+/// A single number `n` can be used instead of a pattern for `n` pixel dashes
+/// with `n` pixel gaps.  A pattern with no lengths, or with only zeros, draws a
+/// solid line.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{dashed_line, DashPattern};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // An 8px dash, a 3px gap, a 2px dot, and another 3px gap, starting 4px in
+/// let pattern = DashPattern::new([8, 3, 2, 3], 4);
+/// dashed_line(&mut image, (0, 0), (399, 399), pattern, Rgba([255, 0, 0, 255]));
+///
+/// // 5px dashes with 5px gaps
+/// dashed_line(&mut image, (0, 399), (399, 0), 5, Rgba([255, 0, 0, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DashPattern {
+    /// Lengths of the dashes and gaps, starting with a dash
+    lengths: Vec<u32>,
+    /// How far into the pattern the line starts
+    offset: u32,
+}
+
+impl DashPattern {
+    /// Creates a pattern from the lengths of its dashes and gaps, starting
+    /// `offset` pixels into the pattern.
+    pub fn new<L>(lengths: L, offset: u32) -> Self
+    where
+        L: Into<Vec<u32>>,
+    {
+        let mut lengths = lengths.into();
+        if lengths.len() % 2 == 1 {
+            lengths.extend_from_within(..);
+        }
+
+        Self { lengths, offset }
+    }
+
+    /// Creates a pattern that draws a solid line.
+    #[must_use]
+    pub fn solid() -> Self {
+        Self {
+            lengths: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// Lengths of the dashes and gaps, starting with a dash.
+    #[must_use]
+    pub fn lengths(&self) -> &[u32] {
+        &self.lengths
+    }
+
+    /// How far into the pattern the line starts.
+    #[must_use]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Total length of the pattern before it repeats.
+    #[must_use]
+    pub fn period(&self) -> u64 {
+        self.lengths.iter().map(|&l| u64::from(l)).sum()
+    }
+
+    /// Whether the pattern draws a solid line.
+    #[must_use]
+    pub fn is_solid(&self) -> bool {
+        self.period() == 0
+    }
+
+    /// Checks whether the pixel `distance` pixels from the start of a line
+    /// falls on a dash rather than a gap.
+    #[must_use]
+    pub fn is_on(&self, distance: u64) -> bool {
+        let period = self.period();
+        if period == 0 {
+            return true;
+        }
+
+        let mut pos = (distance + u64::from(self.offset)) % period;
+        for (i, &l) in self.lengths.iter().enumerate() {
+            if pos < u64::from(l) {
+                return i % 2 == 0;
+            }
+            pos -= u64::from(l);
+        }
+
+        // Not reachable since pos is less than the period
+        true
+    }
+
+    /// The parts of a line `len` pixels long that fall on dashes, as
+    /// distances from the start of the line.  `start` is how far into the
+    /// pattern the line starts, not counting the offset, so that the dashes
+    /// can carry on from a previous line.
+    ///
+    /// Dashes with a length of 0 are included since they can still have caps.
+    pub(crate) fn dashes(&self, start: f64, len: f64) -> Vec<(f64, f64)> {
+        let period = self.period();
+        if period == 0 {
+            return vec![(0.0, len)];
+        }
+
+        // Find where in the pattern the line starts, stopping at dashes with a
+        // length of 0 that are right at the start
+        let mut pos = (start + f64::from(self.offset)).rem_euclid(period as f64);
+        let mut i = 0;
+        loop {
+            let length = f64::from(self.lengths[i]);
+            if pos < length || (length == 0.0 && pos <= 0.0) {
+                break;
+            }
+            pos -= length;
+            i = (i + 1) % self.lengths.len();
+        }
+
+        let mut dashes = Vec::new();
+        let mut t = 0.0;
+        while t <= len {
+            let length = f64::from(self.lengths[i]);
+            let end = t + length - pos;
+            if i % 2 == 0 && (t < len || length == 0.0) {
+                dashes.push((t, end.min(len)));
+            }
+            t = end;
+            pos = 0.0;
+            i = (i + 1) % self.lengths.len();
+        }
+
+        dashes
+    }
+}
+
+impl Default for DashPattern {
+    fn default() -> Self {
+        Self::solid()
+    }
+}
+
+macro_rules! dash_width {
+    ($($t:ty),+) => {
+        $(
+            /// Dashes and gaps that are both `width` pixels long.  A width of
+            /// 0 draws a solid line.
+            impl From<$t> for DashPattern {
+                fn from(width: $t) -> Self {
+                    Self::new([u32::from(width), u32::from(width)], 0)
+                }
+            }
+        )+
+    };
+}
+
+dash_width!(u8, u16, u32);
+
+/// Dashes and gaps that are both `width` pixels long.  A width of 0 or less
+/// draws a solid line.
+impl From<i32> for DashPattern {
+    fn from(width: i32) -> Self {
+        u32::try_from(width).map_or_else(|_| Self::solid(), Self::from)
+    }
+}
+
+impl<const N: usize> From<[u32; N]> for DashPattern {
+    fn from(lengths: [u32; N]) -> Self {
+        Self::new(lengths, 0)
+    }
+}
+
+impl From<&[u32]> for DashPattern {
+    fn from(lengths: &[u32]) -> Self {
+        Self::new(lengths, 0)
+    }
+}
+
+impl From<Vec<u32>> for DashPattern {
+    fn from(lengths: Vec<u32>) -> Self {
+        Self::new(lengths, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DashPattern;
+
+    fn drawn(pattern: &DashPattern, len: u64) -> String {
+        (0..len)
+            .map(|i| if pattern.is_on(i) { '#' } else { '.' })
+            .collect()
+    }
+
+    #[test]
+    fn dash_pattern() {
+        assert_eq!(drawn(&DashPattern::from(2), 10), "##..##..##");
+        assert_eq!(
+            drawn(&DashPattern::new([3, 1, 1, 1], 0), 12),
+            "###.#.###.#."
+        );
+        assert_eq!(
+            drawn(&DashPattern::new([3, 1, 1, 1], 4), 12),
+            "#.###.#.###."
+        );
+        // Odd patterns are repeated
+        assert_eq!(drawn(&DashPattern::new([2, 1, 1], 0), 10), "##.#..#.##");
+    }
+
+    #[test]
+    fn dash_pattern_dashes() {
+        let pattern = DashPattern::new([4, 2, 0, 2], 1);
+        assert_eq!(
+            pattern.dashes(0.0, 12.0),
+            [(0.0, 3.0), (5.0, 5.0), (7.0, 11.0)]
+        );
+        // Carry on from a line that was 3.5 long
+        assert_eq!(pattern.dashes(3.5, 6.0), [(1.5, 1.5), (3.5, 6.0)]);
+
+        assert_eq!(DashPattern::solid().dashes(2.0, 5.0), [(0.0, 5.0)]);
+        assert_eq!(
+            DashPattern::new([0, 4], 0).dashes(0.0, 8.0),
+            [(0.0, 0.0), (4.0, 4.0), (8.0, 8.0)]
+        );
+    }
+
+    #[test]
+    fn dash_pattern_solid() {
+        for pattern in [
+            DashPattern::solid(),
+            DashPattern::from(0),
+            DashPattern::from(-3),
+            DashPattern::new([0, 0], 3),
+        ] {
+            assert!(pattern.is_solid());
+            assert_eq!(drawn(&pattern, 5), "#####");
+        }
+
+        // Zero length gaps also draw a solid line
+        assert_eq!(drawn(&DashPattern::new([2, 0], 0), 5), "#####");
+    }
+}
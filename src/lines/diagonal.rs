@@ -1,6 +1,7 @@
 // These functions are exported publicly in a different module - keep the module prefix
 #![allow(clippy::module_name_repetitions)]
 
+use super::DashPattern;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
 use image::{GenericImage, Rgba, RgbaImage};
@@ -52,7 +53,9 @@ where
 
 /// A dashed diagonal line.
 ///
-/// A `width` of 0 will draw a solid diagonal line.
+/// The [`DashPattern`] starts at `a`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid diagonal line.
 ///
 /// Only points within the image are drawn.
 ///
@@ -68,16 +71,21 @@ where
 /// /// Upwards dashed diagonal line across the image with a 2px dash
 /// diagonal_dashed_line(&mut image, (0, 399), (399, 0), 2, Rgba([255, 0, 0, 255]));
 /// ```
-pub fn diagonal_dashed_line<I, P>(image: &mut I, mut a: P, mut b: P, width: u32, color: I::Pixel)
+pub fn diagonal_dashed_line<I, P, D>(image: &mut I, mut a: P, mut b: P, pattern: D, color: I::Pixel)
 where
     I: GenericImage,
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         crate::lines::diagonal_line(image, a, b, color);
         return;
     }
 
+    // The pattern is measured from `a`, before the end points are swapped
+    let start = a.x();
     if a.x() > b.x() {
         std::mem::swap(&mut a, &mut b);
     }
@@ -90,29 +98,22 @@ where
     let y0 = a.y().min(image.height() - 1);
     let x1 = b.x().min(image.width() - 1);
     let y1 = b.y().min(image.height() - 1);
-    let mut i = 0;
 
     if y0 < y1 {
         let dist = (x1 - x0).min(y1 - y0);
-        while i <= dist {
+        for i in (0..=dist).filter(|&i| pattern.is_on(u64::from((x0 + i).abs_diff(start)))) {
             // This is safe due to the min calls above
             unsafe {
                 image.unsafe_put_pixel(x0 + i, y0 + i, color);
             }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
         }
     } else {
         let dist = (x1 - x0).min(y0 - y1);
-        while i <= dist {
+        for i in (0..=dist).filter(|&i| pattern.is_on(u64::from((x0 + i).abs_diff(start)))) {
             // This is safe due to the min calls above
             unsafe {
                 image.unsafe_put_pixel(x0 + i, y0 - i, color);
             }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
         }
     }
 }
@@ -180,7 +181,9 @@ pub fn diagonal_line_alpha<P>(
 ///
 /// Opacity should be in the range `0..=1`.
 ///
-/// A `width` of 0 will draw a solid diagonal line.
+/// The [`DashPattern`] starts at `a`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid diagonal line.
 ///
 /// Only points within the image are drawn.
 ///
@@ -200,23 +203,28 @@ pub fn diagonal_line_alpha<P>(
 /// /// Upwards dashed diagonal line across the image with a 2px dash and 50% opacity
 /// diagonal_dashed_line_alpha(&mut image, (0, 399), (399, 0), 2, 0.5, Rgba([255, 0, 0, 255]));
 /// ```
-pub fn diagonal_dashed_line_alpha<P>(
+pub fn diagonal_dashed_line_alpha<P, D>(
     image: &mut RgbaImage,
     mut a: P,
     mut b: P,
-    width: u32,
+    pattern: D,
     opacity: f32,
     color: Rgba<u8>,
 ) where
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
     check_opacity!(opacity);
 
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         diagonal_line_alpha(image, a, b, opacity, color);
         return;
     }
 
+    // The pattern is measured from `a`, before the end points are swapped
+    let start = a.x();
     if a.x() > b.x() {
         std::mem::swap(&mut a, &mut b);
     }
@@ -229,29 +237,22 @@ pub fn diagonal_dashed_line_alpha<P>(
     let y0 = a.y().min(image.height() - 1);
     let x1 = b.x().min(image.width() - 1);
     let y1 = b.y().min(image.height() - 1);
-    let mut i = 0;
 
     if y0 < y1 {
         let dist = (x1 - x0).min(y1 - y0);
-        while i <= dist {
+        for i in (0..=dist).filter(|&i| pattern.is_on(u64::from((x0 + i).abs_diff(start)))) {
             // This is safe due to the min calls above
             unsafe {
                 blend_at_unchecked(image, x0 + i, y0 + i, opacity, color);
             }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
         }
     } else {
         let dist = (x1 - x0).min(y0 - y1);
-        while i <= dist {
+        for i in (0..=dist).filter(|&i| pattern.is_on(u64::from((x0 + i).abs_diff(start)))) {
             // This is safe due to the min calls above
             unsafe {
                 blend_at_unchecked(image, x0 + i, y0 - i, opacity, color);
             }
-            let i1 = i + 1;
-            let iw = i + width + 1;
-            i = if i1 % width == 0 { iw } else { i1 };
         }
     }
 }
@@ -278,6 +279,17 @@ mod tests {
 
     mod diagonal_dashed_line {
 
+        test_pixels_changed!(
+            diagonal_dashed_line_reversed,
+            diagonal_dashed_line(
+                (9, 9),
+                (0, 0),
+                crate::lines::DashPattern::new([3, 1, 1, 1], 2)
+            ),
+            10,
+            &*vec![(9, 9), (7, 7), (5, 5), (4, 4), (3, 3), (1, 1)]
+        );
+
         test_pixels_changed!(
             diagonal_dashed_line_0px_width,
             diagonal_dashed_line((0, 0), (10, 10), 0),
@@ -2,6 +2,7 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::many_single_char_names)]
 
+use super::DashPattern;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
 use image::{GenericImage, Rgba, RgbaImage};
@@ -35,7 +36,9 @@ where
 
 /// Draws a dashed horizontal line.
 ///
-/// A `width` of 0 will draw a solid horizontal line.
+/// The [`DashPattern`] starts at `pt`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid horizontal line.
 ///
 /// # Example
 ///
@@ -51,37 +54,34 @@ where
 /// /// Horizontal dashed line across the center of the image with a 2px dash
 /// horizontal_dashed_line(&mut image, (0, 200), 399, 2, color);
 /// ```
-pub fn horizontal_dashed_line<I, P>(image: &mut I, pt: P, mut x2: u32, width: u32, color: I::Pixel)
+pub fn horizontal_dashed_line<I, P, D>(image: &mut I, pt: P, x2: u32, pattern: D, color: I::Pixel)
 where
     I: GenericImage,
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         crate::lines::horizontal_line(image, pt, x2, color);
         return;
     }
 
-    let (mut x0, y) = pt.tuple();
-
-    if x0 > x2 {
-        std::mem::swap(&mut x0, &mut x2);
-    }
+    let (x0, y) = pt.tuple();
+    let (x1, x2) = (x0.min(x2), x0.max(x2));
 
-    if y >= image.height() || x0 >= image.width() {
+    if y >= image.height() || x1 >= image.width() {
         return;
     }
 
-    let x1 = x2.min(image.width() - 1);
-    let mut x = x0.min(image.width() - 1);
-    let mut i = 0;
-
-    while x <= x1 {
+    for x in x1..=x2.min(image.width() - 1) {
+        if !pattern.is_on(u64::from(x.abs_diff(x0))) {
+            continue;
+        }
         // This is safe due to the min calls above
         unsafe {
             image.unsafe_put_pixel(x, y, color);
         }
-        x = if i == width - 1 { x + width + 1 } else { x + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -128,7 +128,9 @@ pub fn horizontal_line_alpha<P>(
 ///
 /// Opacity should be in the range `0..=1`.
 ///
-/// A `width` of 0 will draw a solid horizontal line.
+/// The [`DashPattern`] starts at `pt`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid horizontal line.
 ///
 /// # Panics
 ///
@@ -147,45 +149,42 @@ pub fn horizontal_line_alpha<P>(
 /// /// Horizontal dashed line across the center of the image with a 2px dash and 50% opacity
 /// horizontal_dashed_line_alpha(&mut image, (0, 200), 399, 2, 0.5, color);
 /// ```
-pub fn horizontal_dashed_line_alpha<P>(
+pub fn horizontal_dashed_line_alpha<P, D>(
     image: &mut RgbaImage,
     pt: P,
-    mut x2: u32,
-    width: u32,
+    x2: u32,
+    pattern: D,
     opacity: f32,
     color: Rgba<u8>,
 ) where
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
     check_opacity!(opacity);
 
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         horizontal_line_alpha(image, pt, x2, opacity, color);
         return;
     }
 
-    let (mut x1, y) = pt.tuple();
-    if x1 > x2 {
-        std::mem::swap(&mut x1, &mut x2);
-    }
+    let (x0, y) = pt.tuple();
+    let (x1, x2) = (x0.min(x2), x0.max(x2));
 
-    if y >= image.height() || (x1 >= image.width()) {
-        horizontal_line_alpha(image, pt, x2, opacity, color);
+    if y >= image.height() || x1 >= image.width() {
         return;
     }
 
-    let x2 = x2.min(image.width() - 1);
-    let mut x = x1.min(image.width() - 1);
-    let mut i = 0;
-
-    while x <= x2 {
+    for x in x1..=x2.min(image.width() - 1) {
+        if !pattern.is_on(u64::from(x.abs_diff(x0))) {
+            continue;
+        }
         let (r, g, b) = (color[0], color[1], color[2]);
         // This is safe due to the min calls above
         unsafe {
             blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
         }
-        x = if i == width - 1 { x + width + 1 } else { x + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -211,6 +210,13 @@ mod tests {
 
     mod horizontal_dashed_line {
 
+        test_pixels_changed!(
+            horizontal_dashed_line_reversed,
+            horizontal_dashed_line((9, 0), 0, crate::lines::DashPattern::new([3, 1, 1, 1], 2)),
+            10,
+            &*vec![(9, 0), (7, 0), (5, 0), (4, 0), (3, 0), (1, 0)]
+        );
+
         test_pixels_changed!(
             horizontal_dashed_line_0px,
             horizontal_dashed_line((0, 0), 10, 0),
@@ -235,6 +241,12 @@ mod tests {
             6,
             &*vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]
         );
+        test_pixels_changed!(
+            horizontal_dashed_line_pattern,
+            horizontal_dashed_line((0, 0), 10, crate::lines::DashPattern::new([2, 1, 1, 1], 1)),
+            6,
+            &*vec![(0, 0), (2, 0), (4, 0), (5, 0)]
+        );
         test_pixels_changed!(
             horizontal_dashed_line_bounds,
             horizontal_dashed_line((20, 20), 10, 2),
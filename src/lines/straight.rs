@@ -1,4 +1,5 @@
 use super::bres::LineIter;
+use super::DashPattern;
 use crate::pt::{Point, Pt};
 use image::GenericImage;
 
@@ -33,21 +34,20 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for Pt { x, y } in LineIter::new(a, b) {
-        if (0..width).contains(&x) && (0..height).contains(&y) {
-            // Avoid double checking bounds with unsafe_put_pixel()
-            // This is safe because the bounds have already been checked
-            unsafe {
-                image.unsafe_put_pixel(x as u32, y as u32, color);
-            }
+    clipped(a.pt(), b.pt(), width, height, |x, y, _| {
+        // Avoid double checking bounds with unsafe_put_pixel()
+        // This is safe because the line was clipped to the image
+        unsafe {
+            image.unsafe_put_pixel(x, y, color);
         }
-    }
+    });
 }
 
 /// Draws a dashed straight line between two points.
 /// Points that are outside of the image bounds are ignored.
 ///
-/// If the width is 0 then a solid line is drawn between the two points.
+/// The [`DashPattern`] starts at `a`.  A single number `n` can be used for
+/// `n` pixel dashes with `n` pixel gaps, and a width of 0 draws a solid line.
 ///
 /// # Panics
 ///
@@ -57,25 +57,26 @@ where
 ///
 /// ```
 /// # use image::{RgbaImage, Rgba};
-/// use freehand::lines::dashed_line;
+/// use freehand::lines::{dashed_line, DashPattern};
 /// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
 ///
 /// dashed_line(&mut image, (0, 0), (399, 399), 2, Rgba([255, 0, 0, 255]));
+/// dashed_line(&mut image, (0, 399), (399, 0), DashPattern::new([8, 3, 2, 3], 0), Rgba([255, 0, 0, 255]));
 /// ```
 ///
 /// See also: [`Draw::dashed_line`](crate::Draw::dashed_line)
 ///
-pub fn dashed_line<I, P>(image: &mut I, a: P, b: P, dash_width: u16, color: I::Pixel)
+pub fn dashed_line<I, P, D>(image: &mut I, a: P, b: P, pattern: D, color: I::Pixel)
 where
     I: GenericImage,
     P: Point<i32>,
+    D: Into<DashPattern>,
 {
     check_img_i32!(image);
 
-    let dash_width = dash_width as usize;
-    let w = dash_width * 2;
+    let pattern = pattern.into();
 
-    if dash_width == 0 {
+    if pattern.is_solid() {
         line(image, a, b, color);
         return;
     }
@@ -86,15 +87,15 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let width = image.width() as i32;
 
-    for (i, Pt { x, y }) in LineIter::new(a, b).enumerate() {
-        if (0..width).contains(&x) && (0..height).contains(&y) && i % w < dash_width {
+    clipped(a.pt(), b.pt(), width, height, |x, y, i| {
+        if pattern.is_on(i) {
             // Avoid double checking bounds with unsafe_put_pixel()
-            // This is safe because the bounds have already been checked
+            // This is safe because the line was clipped to the image
             unsafe {
-                image.unsafe_put_pixel(x as u32, y as u32, color);
+                image.unsafe_put_pixel(x, y, color);
             }
         }
-    }
+    });
 }
 
 /// Draws a straight line between two points using a specified opacity.
@@ -132,21 +133,20 @@ where
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for Pt { x, y } in LineIter::new(a, b) {
-        if (0..width).contains(&x) && (0..height).contains(&y) {
-            // Avoid double checking bounds
-            // This is safe because the bounds have already been checked
-            unsafe {
-                blend_at_unchecked(image, x as u32, y as u32, opacity, color);
-            }
+    clipped(a.pt(), b.pt(), width, height, |x, y, _| {
+        // Avoid double checking bounds
+        // This is safe because the line was clipped to the image
+        unsafe {
+            blend_at_unchecked(image, x, y, opacity, color);
         }
-    }
+    });
 }
 
 /// Draws a dashed straight line between two points.
 /// Points that are outside of the image bounds are ignored.
 ///
-/// If the width is 0 then a solid line is drawn between the two points.
+/// The [`DashPattern`] starts at `a`.  A single number `n` can be used for
+/// `n` pixel dashes with `n` pixel gaps, and a width of 0 draws a solid line.
 ///
 /// # Panics
 ///
@@ -167,27 +167,26 @@ where
 ///
 /// See also: [`Draw::dashed_line_alpha`](crate::Draw::dashed_line_alpha)
 ///
-pub fn dashed_line_alpha<P, W>(
+pub fn dashed_line_alpha<P, D>(
     image: &mut image::RgbaImage,
     a: P,
     b: P,
-    dash_width: W,
+    pattern: D,
     opacity: f32,
     color: image::Rgba<u8>,
 ) where
     P: Point<i32>,
-    W: Into<u16>,
+    D: Into<DashPattern>,
 {
     use crate::ops::blend_at_unchecked;
 
     check_img_i32!(image);
     check_opacity!(opacity);
 
-    let dash_width = dash_width.into() as usize;
-    let w = dash_width * 2;
+    let pattern = pattern.into();
 
-    if dash_width == 0 {
-        line(image, a, b, color);
+    if pattern.is_solid() {
+        line_alpha(image, a, b, opacity, color);
         return;
     }
 
@@ -196,20 +195,24 @@ pub fn dashed_line_alpha<P, W>(
     #[allow(clippy::cast_possible_wrap)]
     let height = image.height() as i32;
 
-    for (i, Pt { x, y }) in LineIter::new(a, b).enumerate() {
-        if (0..width).contains(&x) && (0..height).contains(&y) && i % w < dash_width {
+    clipped(a.pt(), b.pt(), width, height, |x, y, i| {
+        if pattern.is_on(i) {
             // Avoid double checking
-            // This is safe because the bounds have already been checked
+            // This is safe because the line was clipped to the image
             unsafe {
-                blend_at_unchecked(image, x as u32, y as u32, opacity, color);
+                blend_at_unchecked(image, x, y, opacity, color);
             }
         }
-    }
+    });
 }
 
 /// Draws a path using straight solid lines from one point to the next.
 /// The start and end points are not connected.
 ///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
 /// # Example
 ///
 /// ```
@@ -228,6 +231,8 @@ where
     P: Point<i32>,
     It: IntoIterator<Item = P>,
 {
+    check_img_i32!(image);
+
     let mut points = points.into_iter();
 
     let mut a = match points.next() {
@@ -235,12 +240,114 @@ where
         None => return,
     };
 
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+
+    for b in points {
+        clipped(a.pt(), b.pt(), width, height, |x, y, _| {
+            // This is safe because the line was clipped to the image
+            unsafe {
+                image.unsafe_put_pixel(x, y, color);
+            }
+        });
+        a = b;
+    }
+}
+
+/// Draws a dashed path using straight lines from one point to the next.
+/// The start and end points are not connected.
+///
+/// The [`DashPattern`] starts at the first point and carries on from one line
+/// to the next instead of starting over at every point.  Points that are
+/// outside of the image bounds are ignored.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{dashed_path, DashPattern};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let lines = [(0, 0), (399, 0), (399, 399), (0, 399)];
+/// dashed_path(&mut image, lines, DashPattern::new([8, 3, 2, 3], 0), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::dashed_path`](crate::Draw::dashed_path)
+pub fn dashed_path<I, P, It, D>(image: &mut I, points: It, pattern: D, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+    D: Into<DashPattern>,
+{
+    check_img_i32!(image);
+
+    let pattern = pattern.into();
+    let mut points = points.into_iter();
+
+    let mut a = match points.next() {
+        Some(first) => first.pt(),
+        None => return,
+    };
+
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+
+    // Distance along the path to the start of the current line
+    let mut start = 0;
+
     for b in points {
-        line(image, a.pt(), b.pt(), color);
+        let b = b.pt();
+        clipped(a, b, width, height, |x, y, i| {
+            // Lines after the first skip the point they share with the last line
+            if (i > 0 || start == 0) && pattern.is_on(start + i) {
+                // This is safe because the line was clipped to the image
+                unsafe {
+                    image.unsafe_put_pixel(x, y, color);
+                }
+            }
+        });
+        start += major_len(a, b);
         a = b;
     }
 }
 
+/// Number of steps along the major axis from `a` to `b`.
+fn major_len(a: Pt<i32>, b: Pt<i32>) -> u64 {
+    let dx = (i64::from(b.x()) - i64::from(a.x())).unsigned_abs();
+    let dy = (i64::from(b.y()) - i64::from(a.y())).unsigned_abs();
+    dx.max(dy)
+}
+
+/// Calls `put` with each point on the line from `a` to `b` that is inside of
+/// a `width` by `height` image and the point's distance from `a`, in pixels
+/// along the line.
+///
+/// The line is clipped to the image first, so points outside of the image
+/// are never stepped through.
+fn clipped<F>(a: Pt<i32>, b: Pt<i32>, width: i32, height: i32, mut put: F)
+where
+    F: FnMut(u32, u32, u64),
+{
+    let mut line = LineIter::new(a, b);
+    // LineIter starts at the end with the smaller major axis coordinate
+    let reversed = line.pt() != a;
+    let last = major_len(a, b);
+
+    let skipped = line.clip((0, 0), (width - 1, height - 1));
+    for (i, Pt { x, y }) in (skipped..).zip(line) {
+        put(x as u32, y as u32, if reversed { last - i } else { i });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -327,6 +434,46 @@ mod tests {
             6,
             &*vec![(0, 0), (0, 1), (1, 2), (1, 3), (2, 4)]
         );
+
+        test_pixels_changed!(
+            dashed_line_pattern,
+            dashed_line(
+                (0, 0),
+                (9, 0),
+                crate::lines::DashPattern::new([3, 1, 1, 1], 0)
+            ),
+            10,
+            &*vec![(0, 0), (1, 0), (2, 0), (4, 0), (6, 0), (7, 0), (8, 0)]
+        );
+
+        test_pixels_changed!(
+            dashed_line_pattern_reversed,
+            dashed_line(
+                (9, 0),
+                (0, 0),
+                crate::lines::DashPattern::new([3, 1, 1, 1], 2)
+            ),
+            10,
+            &*vec![(9, 0), (7, 0), (5, 0), (4, 0), (3, 0), (1, 0)]
+        );
+
+        #[test]
+        fn dashed_line_alpha_solid() {
+            // Solid patterns are still blended with the opacity
+            let color = image::Rgba([255, 0, 0, 255]);
+            for pattern in [
+                crate::lines::DashPattern::solid(),
+                0.into(),
+                crate::lines::DashPattern::new([0, 0], 0),
+            ] {
+                let mut dashed = crate::test::img::blank((10, 10));
+                let mut solid = crate::test::img::blank((10, 10));
+                super::super::dashed_line_alpha(&mut dashed, (0, 2), (9, 7), pattern, 0.5, color);
+                super::super::line_alpha(&mut solid, (0, 2), (9, 7), 0.5, color);
+                assert_eq!(dashed, solid);
+                assert_ne!(*dashed.get_pixel(0, 2), color);
+            }
+        }
     }
 
     mod path {
@@ -344,5 +491,35 @@ mod tests {
             super::super::path(&mut image, lines, image::Rgba([255, 0, 0, 255]));
             image.save("images/path.png")
         }
+
+        #[test]
+        fn dashed_path_continues() {
+            let color = image::Luma([255]);
+            let pattern = crate::lines::DashPattern::new([5, 2], 1);
+            let points = [(2, 2), (9, 2), (9, 12), (3, 12)];
+
+            let mut image = image::GrayImage::new(15, 15);
+            super::super::dashed_path(&mut image, points, pattern.clone(), color);
+
+            // Walk the path one pixel at a time, counting shared corners once
+            let mut walk = vec![];
+            for x in 2..=9 {
+                walk.push((x, 2));
+            }
+            for y in 3..=12 {
+                walk.push((9, y));
+            }
+            for x in (3..=8).rev() {
+                walk.push((x, 12));
+            }
+
+            for (i, (x, y)) in (0..).zip(walk) {
+                assert_eq!(
+                    *image.get_pixel(x, y) == color,
+                    pattern.is_on(i),
+                    "({x}, {y})"
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,667 @@
+use crate::ops::blend_at_unchecked;
+use crate::{Point, Pt};
+
+/// The shape drawn at the ends of a thick line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    /// The line stops at its end points.
+    Butt,
+    /// The line continues past each end point by half of its width.
+    Square,
+    /// Each end is rounded off with a half circle as wide as the line.
+    Round,
+}
+
+/// The shape drawn where two lines of a stroke meet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineJoin {
+    /// The outer edges of the lines are extended until they meet in a point.
+    ///
+    /// The value is the miter limit: the longest a miter can be, as a ratio of
+    /// its length (from the inner corner to its point) to the width of the
+    /// line.  Sharper corners use [`LineJoin::Bevel`] instead.  SVG uses a
+    /// limit of 4.0 by default.
+    Miter(f32),
+    /// The outer corner is rounded off with a circle as wide as the line.
+    Round,
+    /// The outer corners of the lines are connected by a straight edge.
+    Bevel,
+}
+
+/// A straight piece of a stroke with a width and a cap at both ends.
+///
+/// Strokes are antialiased by their signed distance: how far a pixel's center
+/// is outside (positive) or inside (negative) of the stroke.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Segment {
+    /// Start point
+    a: Pt<f64>,
+    /// Unit vector from the start point to the end point
+    dir: Pt<f64>,
+    /// Distance between the end points
+    len: f64,
+    /// Half of the width
+    half: f64,
+    cap: LineCap,
+}
+
+impl Segment {
+    /// Creates a segment from `a` to `b`.  A segment whose end points are the
+    /// same is treated as a horizontal line with a length of zero.
+    pub(crate) fn new(a: Pt<f64>, b: Pt<f64>, width: f64, cap: LineCap) -> Self {
+        let d = b - a;
+        let len = d.x().hypot(d.y());
+        let dir = if len > 0.0 {
+            Pt::new(d.x() / len, d.y() / len)
+        } else {
+            Pt::new(1.0, 0.0)
+        };
+
+        Self {
+            a,
+            dir,
+            len,
+            half: width / 2.0,
+            cap,
+        }
+    }
+
+    /// Signed distance from the edge of the segment to a point.
+    pub(crate) fn distance(&self, pt: Pt<f64>) -> f64 {
+        let p = pt - self.a;
+        let along = p.x() * self.dir.x() + p.y() * self.dir.y();
+        let across = (p.y() * self.dir.x() - p.x() * self.dir.y()).abs();
+
+        match self.cap {
+            LineCap::Round => {
+                let along = along.clamp(0.0, self.len) - along;
+                along.hypot(across) - self.half
+            }
+            LineCap::Butt | LineCap::Square => {
+                // Distance to a rectangle centered on the middle of the segment
+                let ext = if self.cap == LineCap::Square {
+                    self.half
+                } else {
+                    0.0
+                };
+                let qx = (along - self.len / 2.0).abs() - self.len / 2.0 - ext;
+                let qy = across - self.half;
+                qx.max(0.0).hypot(qy.max(0.0)) + qx.max(qy).min(0.0)
+            }
+        }
+    }
+
+    /// Smallest and largest corners of a box around every pixel the segment
+    /// can cover.
+    pub(crate) fn bounds(&self) -> (Pt<f64>, Pt<f64>) {
+        // Enough for square caps on a diagonal line, plus a pixel of fringe
+        let pad = self.half * std::f64::consts::SQRT_2 + 1.0;
+        let b = Pt::new(
+            self.a.x() + self.dir.x() * self.len,
+            self.a.y() + self.dir.y() * self.len,
+        );
+
+        (
+            Pt::new(self.a.x().min(b.x()) - pad, self.a.y().min(b.y()) - pad),
+            Pt::new(self.a.x().max(b.x()) + pad, self.a.y().max(b.y()) + pad),
+        )
+    }
+
+    /// First and last column of the pixels in row `y` that the segment can
+    /// cover, or `None` if it does not reach the row.
+    pub(crate) fn span(&self, y: f64) -> Option<(i64, i64)> {
+        // The rectangle around the segment and its caps, plus a pixel of fringe
+        let ext = if self.cap == LineCap::Butt {
+            1.0
+        } else {
+            self.half + 1.0
+        };
+        let half = self.half + 1.0;
+        let py = y - self.a.y();
+        let (dx, dy) = (self.dir.x(), self.dir.y());
+
+        // Where `k * x + c` is between `lo` and `hi`
+        let range = |k: f64, c: f64, lo: f64, hi: f64| {
+            if k.abs() < f64::EPSILON {
+                (lo..=hi)
+                    .contains(&c)
+                    .then(|| (f64::NEG_INFINITY, f64::INFINITY))
+            } else {
+                let (x0, x1) = ((lo - c) / k, (hi - c) / k);
+                Some((x0.min(x1), x0.max(x1)))
+            }
+        };
+
+        // Distance along and across the segment, as functions of x
+        let (a0, a1) = range(dx, py * dy - self.a.x() * dx, -ext, self.len + ext)?;
+        let (b0, b1) = range(-dy, py * dx + self.a.x() * dy, -half, half)?;
+
+        let x0 = a0.max(b0).ceil();
+        let x1 = a1.min(b1).floor();
+        (x0 <= x1).then(|| (x0 as i64, x1 as i64))
+    }
+
+    /// Blends the segment into the image by itself, one row at a time, without
+    /// keeping the coverage of its pixels.
+    pub(crate) fn blend(&self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        let (min, max) = self.bounds();
+        let y0 = (min.y().floor() as i64).max(0);
+        let y1 = (max.y().ceil() as i64).min(i64::from(image.height()) - 1);
+        let width = i64::from(image.width());
+
+        for y in y0..=y1 {
+            let (x0, x1) = match self.span(y as f64) {
+                Some((x0, x1)) => (x0.max(0), x1.min(width - 1)),
+                None => continue,
+            };
+            for x in x0..=x1 {
+                let c = (0.5 - self.distance(Pt::new(x as f64, y as f64))).clamp(0.0, 1.0);
+                if c > 0.0 {
+                    // The rows and columns are clamped to the image
+                    unsafe {
+                        blend_at_unchecked(image, x as u32, y as u32, c as f32, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The largest coverage of each pixel in an area of an image, for strokes
+/// made from several overlapping pieces.
+///
+/// Every piece is added before anything is drawn, so each pixel is blended
+/// only once no matter how many pieces cover it.  Each row only keeps the
+/// columns that its pieces reach, so long diagonal strokes do not need room
+/// for every pixel of their bounding box.
+pub(crate) struct Coverage {
+    /// Top left corner of the area
+    min: Pt<i64>,
+    /// Bottom right corner of the area
+    max: Pt<i64>,
+    /// The covered pixels of each row in the area
+    rows: Vec<Row>,
+}
+
+/// Coverage of the pixels in a row of a [`Coverage`], starting at `x`.
+#[derive(Default)]
+struct Row {
+    x: i64,
+    cov: Vec<f32>,
+}
+
+impl Coverage {
+    /// Creates a [`Coverage`] for the part of `(min, max)` inside of the image.
+    pub(crate) fn new(image: &image::RgbaImage, (min, max): (Pt<f64>, Pt<f64>)) -> Self {
+        let x0 = (min.x().floor() as i64).max(0);
+        let y0 = (min.y().floor() as i64).max(0);
+        let x1 = (max.x().ceil() as i64).min(i64::from(image.width()) - 1);
+        let y1 = (max.y().ceil() as i64).min(i64::from(image.height()) - 1);
+
+        let mut rows = Vec::new();
+        rows.resize_with((y1 - y0 + 1).max(0) as usize, Row::default);
+
+        Self {
+            min: Pt::new(x0, y0),
+            max: Pt::new(x1, y1),
+            rows,
+        }
+    }
+
+    /// Rows of the area that are also inside of `(min, max)`
+    fn rows(&self, (min, max): (Pt<f64>, Pt<f64>)) -> std::ops::RangeInclusive<i64> {
+        let y0 = (min.y().floor() as i64).max(self.min.y());
+        let y1 = (max.y().ceil() as i64).min(self.max.y());
+        y0..=y1
+    }
+
+    /// Adds the pixels from `x0` to `x1` in row `y` of a piece.
+    fn add_span<F>(&mut self, y: i64, x0: i64, x1: i64, distance: F)
+    where
+        F: Fn(Pt<f64>) -> f64,
+    {
+        let x0 = x0.max(self.min.x());
+        let x1 = x1.min(self.max.x());
+        if x0 > x1 {
+            return;
+        }
+
+        let row = &mut self.rows[(y - self.min.y()) as usize];
+        if row.cov.is_empty() {
+            row.x = x0;
+        } else if x0 < row.x {
+            let grow = (row.x - x0) as usize;
+            row.cov.splice(0..0, std::iter::repeat(0.0).take(grow));
+            row.x = x0;
+        }
+        let len = (x1 - row.x + 1) as usize;
+        if len > row.cov.len() {
+            row.cov.resize(len, 0.0);
+        }
+
+        for x in x0..=x1 {
+            let c = (0.5 - distance(Pt::new(x as f64, y as f64))).clamp(0.0, 1.0) as f32;
+            let i = (x - row.x) as usize;
+            if c > row.cov[i] {
+                row.cov[i] = c;
+            }
+        }
+    }
+
+    /// Adds a piece that fits in `(min, max)`.  `distance` gives the signed
+    /// distance from the edge of the piece to a pixel's center.
+    pub(crate) fn add<F>(&mut self, (min, max): (Pt<f64>, Pt<f64>), distance: F)
+    where
+        F: Fn(Pt<f64>) -> f64,
+    {
+        let (x0, x1) = (min.x().floor() as i64, max.x().ceil() as i64);
+        for y in self.rows((min, max)) {
+            self.add_span(y, x0, x1, &distance);
+        }
+    }
+
+    /// Adds a [`Segment`], only looking at the pixels near it in each row.
+    pub(crate) fn add_segment(&mut self, seg: &Segment) {
+        for y in self.rows(seg.bounds()) {
+            if let Some((x0, x1)) = seg.span(y as f64) {
+                self.add_span(y, x0, x1, |pt| seg.distance(pt));
+            }
+        }
+    }
+
+    /// Blends every covered pixel into the image.
+    pub(crate) fn blend(&self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        for (y, row) in (self.min.y()..).zip(&self.rows) {
+            for (x, &c) in (row.x..).zip(&row.cov) {
+                if c > 0.0 {
+                    // The area is clamped to the image when created
+                    unsafe {
+                        blend_at_unchecked(image, x as u32, y as u32, c, color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Signed distance from the edge of a polygon to a point.
+fn polygon_distance(corners: &[Pt<f64>], pt: Pt<f64>) -> f64 {
+    let mut dist = f64::INFINITY;
+    let mut inside = false;
+
+    let mut prev = corners[corners.len() - 1];
+    for &a in corners {
+        let b = prev;
+        prev = a;
+        let e = b - a;
+        let w = pt - a;
+
+        // Closest point on the edge
+        let len = e.x() * e.x() + e.y() * e.y();
+        let t = if len > 0.0 {
+            ((w.x() * e.x() + w.y() * e.y()) / len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        dist = dist.min((w.x() - e.x() * t).hypot(w.y() - e.y() * t));
+
+        // Count the edges crossed going right from the point
+        let crosses = (a.y() <= pt.y()) != (b.y() <= pt.y());
+        if crosses && (e.x() * w.y() > e.y() * w.x()) == (b.y() > a.y()) {
+            inside = !inside;
+        }
+    }
+
+    if inside {
+        -dist
+    } else {
+        dist
+    }
+}
+
+/// Smallest convex polygon containing all of the points, going around it in
+/// order.
+fn convex_hull(mut points: Vec<Pt<f64>>) -> Vec<Pt<f64>> {
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let turns_left = |a: Pt<f64>, b: Pt<f64>, c: Pt<f64>| {
+        (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x()) > 0.0
+    };
+
+    // Lower half from left to right, then upper half from right to left
+    let mut hull: Vec<Pt<f64>> = Vec::with_capacity(points.len() + 1);
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2
+                && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], p)
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point starts the other half
+        hull.pop();
+    }
+
+    hull
+}
+
+/// Joins a line coming into `v` going in the direction `d1` to a line
+/// leaving `v` going in the direction `d2`.
+///
+/// The pieces for the lines end exactly at `v`, so pixels along their ends
+/// would only be half covered by each.  Joins also cover the last pixel of
+/// each line to fill them in.
+fn add_join(cov: &mut Coverage, v: Pt<f64>, d1: Pt<f64>, d2: Pt<f64>, width: f64, join: LineJoin) {
+    if join == LineJoin::Round {
+        cov.add_segment(&Segment::new(v, v, width, LineCap::Round));
+        return;
+    }
+
+    let half = width / 2.0;
+    // Normals pointing to the outside of the turn
+    let cross = d1.x() * d2.y() - d1.y() * d2.x();
+    let side = if cross > 0.0 { -half } else { half };
+    let o1 = Pt::new(-d1.y(), d1.x()).mul(side);
+    let o2 = Pt::new(-d2.y(), d2.x()).mul(side);
+
+    let mut corners = vec![
+        v + o1,
+        v - o1,
+        v + o1 - d1,
+        v - o1 - d1,
+        v + o2,
+        v - o2,
+        v + o2 + d2,
+        v - o2 + d2,
+    ];
+
+    if let LineJoin::Miter(limit) = join {
+        // 1 + cos of the angle between the normals, scaled by half²
+        let cos = half * half + o1.x() * o2.x() + o1.y() * o2.y();
+        if cos > 0.0 && (2.0 * half * half / cos).sqrt() <= f64::from(limit) {
+            corners.push(v + (o1 + o2).mul(half * half / cos));
+        }
+    }
+
+    let corners = convex_hull(corners);
+    let pad = Pt::new(1.0, 1.0);
+    let min = corners
+        .iter()
+        .fold(v, |m, p| Pt::new(m.x().min(p.x()), m.y().min(p.y())));
+    let max = corners
+        .iter()
+        .fold(v, |m, p| Pt::new(m.x().max(p.x()), m.y().max(p.y())));
+    cov.add((min - pad, max + pad), |pt| polygon_distance(&corners, pt));
+}
+
+/// Draws an antialiased line that is `width` pixels wide through each of
+/// the points, with `join` where the lines meet and `cap` at both ends.
+///
+/// Closed strokes also connect the last point to the first and have no caps.
+/// Every pixel is blended once, so the joints are not darker than the rest
+/// of the stroke.  Repeated points are skipped.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{polyline_stroke, LineCap, LineJoin};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let points = [(20.0, 380.0), (120.0, 150.5), (250.0, 300.0), (380.0, 20.0)];
+/// polyline_stroke(
+///     &mut image,
+///     points,
+///     6.0,
+///     LineJoin::Miter(4.0),
+///     LineCap::Butt,
+///     false,
+///     Rgba([255, 0, 0, 255]),
+/// );
+/// ```
+///
+/// See also: [`Draw::polyline_stroke`](crate::Draw::polyline_stroke)
+///
+pub fn polyline_stroke<P, T, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    width: f32,
+    join: LineJoin,
+    cap: LineCap,
+    closed: bool,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    It: IntoIterator<Item = P>,
+{
+    let mut pts: Vec<Pt<f64>> = Vec::new();
+    for p in points {
+        let p = Pt::new(p.x().into(), p.y().into());
+        if pts.last() != Some(&p) {
+            pts.push(p);
+        }
+    }
+    if closed && pts.len() > 1 && pts.first() == pts.last() {
+        pts.pop();
+    }
+
+    let first = match pts.first() {
+        Some(&first) if width > 0.0 => first,
+        _ => return,
+    };
+    let width = f64::from(width);
+
+    let mut segs: Vec<Segment> = pts
+        .windows(2)
+        .map(|w| Segment::new(w[0], w[1], width, LineCap::Butt))
+        .collect();
+    if closed && pts.len() > 2 {
+        segs.push(Segment::new(
+            pts[pts.len() - 1],
+            first,
+            width,
+            LineCap::Butt,
+        ));
+    }
+
+    let bounds = segs.iter().map(Segment::bounds).fold(
+        Segment::new(first, first, width, LineCap::Square).bounds(),
+        |(a, b), (c, d)| {
+            (
+                Pt::new(a.x().min(c.x()), a.y().min(c.y())),
+                Pt::new(b.x().max(d.x()), b.y().max(d.y())),
+            )
+        },
+    );
+    // Square caps and miters can reach past the lines' bounds
+    let reach = match join {
+        LineJoin::Miter(limit) => f64::from(limit).max(1.0) * width,
+        _ => width,
+    };
+    let pad = Pt::new(reach, reach);
+    let mut cov = Coverage::new(image, (bounds.0 - pad, bounds.1 + pad));
+
+    for seg in &segs {
+        cov.add_segment(seg);
+    }
+
+    if closed {
+        if segs.len() > 2 {
+            for (i, seg) in segs.iter().enumerate() {
+                let next = &segs[(i + 1) % segs.len()];
+                add_join(&mut cov, next.a, seg.dir, next.dir, width, join);
+            }
+        }
+    } else {
+        for w in segs.windows(2) {
+            add_join(&mut cov, w[1].a, w[0].dir, w[1].dir, width, join);
+        }
+
+        let last = pts[pts.len() - 1];
+        let (d1, d2) = match (segs.first(), segs.last()) {
+            (Some(a), Some(b)) => (a.dir, b.dir),
+            _ => (Pt::new(1.0, 0.0), Pt::new(1.0, 0.0)),
+        };
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let half = width / 2.0;
+                // Overlap the lines so their ends are filled in
+                let start = Segment::new(first - d1.mul(half), first + d1, width, LineCap::Butt);
+                let end = Segment::new(last - d2, last + d2.mul(half), width, LineCap::Butt);
+                cov.add_segment(&start);
+                cov.add_segment(&end);
+            }
+            LineCap::Round => {
+                cov.add_segment(&Segment::new(first, first, width, LineCap::Round));
+                cov.add_segment(&Segment::new(last, last, width, LineCap::Round));
+            }
+        }
+    }
+
+    cov.blend(image, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{polyline_stroke, LineCap, LineJoin, Segment};
+    use crate::Pt;
+
+    #[test]
+    fn segment_distance() {
+        let a = Pt::new(10.0, 10.0);
+        let b = Pt::new(20.0, 10.0);
+
+        let butt = Segment::new(a, b, 4.0, LineCap::Butt);
+        let square = Segment::new(a, b, 4.0, LineCap::Square);
+        let round = Segment::new(a, b, 4.0, LineCap::Round);
+
+        for seg in [butt, square, round] {
+            assert!((seg.distance(Pt::new(15.0, 10.0)) + 2.0).abs() < 1e-9);
+            assert!((seg.distance(Pt::new(15.0, 14.0)) - 2.0).abs() < 1e-9);
+        }
+
+        // Past the end of the line
+        let pt = Pt::new(22.0, 10.0);
+        assert!((butt.distance(pt) - 2.0).abs() < 1e-9);
+        assert!(square.distance(pt).abs() < 1e-9);
+        assert!(round.distance(pt).abs() < 1e-9);
+
+        let corner = Pt::new(22.0, 12.0);
+        assert!(square.distance(corner).abs() < 1e-9);
+        assert!((round.distance(corner) - (8f64.sqrt() - 2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn segment_span() {
+        // Every pixel the segment covers is inside of its span for the row
+        for (a, b) in [
+            ((3.0, 4.0), (35.5, 30.0)),
+            ((20.0, 2.0), (20.0, 37.0)),
+            ((2.0, 20.5), (38.0, 20.5)),
+            ((30.25, 3.0), (6.0, 36.75)),
+            ((20.0, 20.0), (20.0, 20.0)),
+        ] {
+            for cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+                let seg = Segment::new(Pt::new(a.0, a.1), Pt::new(b.0, b.1), 5.5, cap);
+                for y in 0..40 {
+                    let span = seg.span(f64::from(y));
+                    for x in 0..40 {
+                        if seg.distance(Pt::new(f64::from(x), f64::from(y))) < 0.5 {
+                            let (x0, x1) = span.expect("row is not covered");
+                            assert!((x0..=x1).contains(&i64::from(x)), "({x}, {y}) {cap:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polyline_stroke_joins() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let red = image::Rgba([255, 0, 0, 255]);
+        let zigzag = |x: f64| {
+            [
+                (x, 380.0),
+                (x + 40.0, 40.0),
+                (x + 80.0, 380.0),
+                (x + 100.0, 200.5),
+            ]
+        };
+
+        for (i, join) in (0..).zip([LineJoin::Miter(10.0), LineJoin::Round, LineJoin::Bevel]) {
+            let x = 20.0 + 125.0 * f64::from(i);
+            polyline_stroke(
+                &mut image,
+                zigzag(x),
+                12.0,
+                join,
+                LineCap::Round,
+                false,
+                red,
+            );
+        }
+        image.save("images/polyline_stroke.png")
+    }
+
+    #[test]
+    fn polyline_stroke_blends_once() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let mut line = crate::test::img::blank((40, 20));
+        let mut stroke = crate::test::img::blank((40, 20));
+
+        crate::lines::antialiased_line_capped(
+            &mut line,
+            (3, 10),
+            (37, 10),
+            5.5,
+            LineCap::Round,
+            red,
+        );
+        polyline_stroke(
+            &mut stroke,
+            [(3, 10), (20, 10), (20, 10), (37, 10)],
+            5.5,
+            LineJoin::Round,
+            LineCap::Round,
+            false,
+            red,
+        );
+        assert_eq!(line, stroke);
+    }
+
+    #[test]
+    fn polyline_stroke_corners() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let corner = |join, closed| {
+            let mut image = crate::test::img::blank((40, 40));
+            let points = [(10, 10), (30, 10), (30, 30), (10, 30)];
+            polyline_stroke(&mut image, points, 8.0, join, LineCap::Butt, closed, red);
+            move |x, y| *image.get_pixel(x, y) == red
+        };
+
+        // The outer corner at (30, 10) is only filled by a miter
+        let miter = corner(LineJoin::Miter(4.0), false);
+        assert!(miter(33, 7) && miter(30, 7) && miter(33, 10));
+        let round = corner(LineJoin::Round, false);
+        assert!(!round(33, 7) && round(32, 8) && round(30, 7));
+        let bevel = corner(LineJoin::Bevel, false);
+        assert!(!bevel(32, 8) && bevel(30, 7) && bevel(33, 10));
+
+        // A right angle is too sharp for a limit of 1.4
+        let limited = corner(LineJoin::Miter(1.4), false);
+        assert!(!limited(33, 7) && !limited(32, 8));
+
+        // Open strokes leave a gap between the ends, closed strokes do not
+        let open = corner(LineJoin::Miter(4.0), false);
+        assert!(!open(10, 20) && !open(7, 7));
+        let closed = corner(LineJoin::Miter(4.0), true);
+        assert!(closed(10, 20) && closed(7, 7) && closed(7, 33));
+    }
+}
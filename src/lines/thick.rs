@@ -1,11 +1,168 @@
 // These functions are exported publicly in a different module - keep the module prefix
 #![allow(clippy::module_name_repetitions)]
 
-use crate::ops::blend_at;
+use super::stroke::{Coverage, LineCap, Segment};
+use super::{DashPattern, LineIter};
 use crate::{Point, Pt};
+use image::GenericImage;
+
+/// Draws a line with a thickness of `width` pixels.
+///
+/// Each pixel found by [`LineIter`] is widened into a run of pixels across
+/// the line, along whichever axis the line is furthest from, so that the line
+/// is `width` pixels thick when measured perpendicular to the line.  The ends
+/// of the line are cut along that same axis.
+///
+/// A width of 1 draws the same pixels as [`line`](super::line) and a width
+/// of 0 draws nothing.  The line is clipped to the image first, so points
+/// that are outside of the image bounds cost nothing.
+///
+/// # Example
+///
+/// ```
+/// # use image::{GrayImage, Luma};
+/// use freehand::lines::thick_line;
+/// # let mut image = GrayImage::new(400, 400);
+///
+/// thick_line(&mut image, (10, 10), (390, 200), 5, Luma([255]));
+/// ```
+///
+/// See also: [`Draw::thick_line`](crate::Draw::thick_line)
+///
+pub fn thick_line<I, P, T>(image: &mut I, a: P, b: P, width: u16, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<T>,
+    T: Into<i32> + Copy,
+{
+    let a = Pt::new(a.x().into(), a.y().into());
+    let b = Pt::new(b.x().into(), b.y().into());
+
+    if width == 0 {
+        return;
+    }
+
+    // Work with major and minor axis coordinates, like LineIter does
+    let steep = (a.x() - b.x()).abs() < (a.y() - b.y()).abs();
+    let axes = |pt: Pt<i32>| if steep { pt.transpose() } else { pt };
+    let (ma, mb) = (axes(a), axes(b));
+
+    let slope = if ma.x() == mb.x() {
+        0.0
+    } else {
+        (mb.y() - ma.y()) as f64 / (mb.x() - ma.x()) as f64
+    };
+
+    // Pixels across the line along the minor axis
+    let count = (f64::from(width) * slope.hypot(1.0)).round().max(1.0) as i32;
+
+    // Skip the points whose runs can not reach the image
+    let mut line = LineIter::new(a, b);
+    #[allow(clippy::cast_possible_wrap)]
+    let max = axes(Pt::new(image.width() as i32 - 1, image.height() as i32 - 1));
+    line.clip(
+        axes(Pt::new(0, -count)),
+        axes(Pt::new(max.x(), max.y() + count)),
+    );
+
+    for pt in line {
+        let Pt { x: u, y: v } = axes(pt);
+
+        // An extra pixel for an even count goes on the side of the exact line
+        let exact = ma.y() as f64 + (u - ma.x()) as f64 * slope;
+        let lo = if count % 2 == 1 || exact >= v as f64 {
+            v - (count - 1) / 2
+        } else {
+            v - count / 2
+        };
+
+        for v in lo..lo + count {
+            let Pt { x, y } = axes(Pt::new(u, v));
+            if let (Ok(x), Ok(y)) = (u32::try_from(x), u32::try_from(y)) {
+                if x < image.width() && y < image.height() {
+                    image.put_pixel(x, y, color);
+                }
+            }
+        }
+    }
+}
+
+/// Draws a line with a thickness of `width` pixels and the given
+/// [`LineCap`] at both ends.
+///
+/// [`LineCap::Butt`] draws the same pixels as [`thick_line`].
+/// [`LineCap::Square`] extends the line past each end point by half of its
+/// width and [`LineCap::Round`] adds a filled circle as wide as the line to
+/// each end point.
+///
+/// # Example
+///
+/// ```
+/// # use image::{GrayImage, Luma};
+/// use freehand::lines::{thick_line_capped, LineCap};
+/// # let mut image = GrayImage::new(400, 400);
+///
+/// thick_line_capped(&mut image, (10, 10), (390, 200), 9, LineCap::Round, Luma([255]));
+/// ```
+///
+/// See also: [`Draw::thick_line_capped`](crate::Draw::thick_line_capped)
+///
+pub fn thick_line_capped<I, P, T>(
+    image: &mut I,
+    a: P,
+    b: P,
+    width: u16,
+    cap: LineCap,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    P: Point<T>,
+    T: Into<i32> + Copy,
+{
+    let a = Pt::new(a.x().into(), a.y().into());
+    let b = Pt::new(b.x().into(), b.y().into());
+    let half = f64::from(width) / 2.0;
+
+    match cap {
+        LineCap::Butt => thick_line(image, a, b, width, color),
+        LineCap::Square => {
+            let d = Pt::<f64>::from(b - a);
+            let len = d.x().hypot(d.y());
+            // Odd widths are centered on the line, so extending each end by
+            // whole pixels makes the cap as long as the line is wide
+            let ext = f64::from(width / 2);
+            // A single point is extended horizontally into a square
+            let d = if len > 0.0 {
+                Pt::new(d.x() * ext / len, d.y() * ext / len)
+            } else {
+                Pt::new(ext, 0.0)
+            };
+            thick_line(
+                image,
+                (Pt::<f64>::from(a) - d).i32(),
+                (Pt::<f64>::from(b) + d).i32(),
+                width,
+                color,
+            );
+        }
+        LineCap::Round => {
+            thick_line(image, a, b, width, color);
+            if width > 0 {
+                for end in [a, b] {
+                    crate::conics::circle_filled(image, half, Pt::<f64>::from(end), color);
+                }
+            }
+        }
+    }
+}
 
 /// Draws an antialiased line of the specified thickness
 ///
+/// Every pixel is blended by how much of it is covered by the line, like
+/// [`antialiased_line_capped`] with [`LineCap::Butt`], so the end points can
+/// be anywhere inside of a pixel and lines that move slowly do not jump from
+/// one pixel to the next.
+///
 /// # Example
 ///
 /// ```
@@ -14,90 +171,270 @@ use crate::{Point, Pt};
 /// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
 ///
 /// antialiased_line(&mut image, (0, 0), (399, 399), 4.5, Rgba([255, 0, 0, 255]));
+/// antialiased_line(&mut image, (0.5, 20.0), (379.25, 399.0), 2.0, Rgba([255, 0, 0, 255]));
 /// ```
 ///
 /// See also: [`Draw::antialiased_line`](crate::Draw::antialiased_line)
 ///
-// http://members.chello.at/~easyfilter/bresenham.html
-// http://members.chello.at/~easyfilter/canvas.html
 pub fn antialiased_line<P, T>(
     image: &mut image::RgbaImage,
     a: P,
     b: P,
-    wd: f32,
+    width: f32,
     color: image::Rgba<u8>,
 ) where
     P: Point<T>,
-    T: Into<i32> + Copy,
+    T: Into<f64> + Copy,
 {
-    let Pt {
-        x: mut x0,
-        y: mut y0,
-    } = Pt::new(a.x().into(), a.y().into());
+    antialiased_line_capped(image, a, b, width, LineCap::Butt, color);
+}
 
-    let Pt { x: x1, y: y1 } = Pt::new(b.x().into(), b.y().into());
+/// Draws an antialiased line that is `width` pixels wide with the given
+/// [`LineCap`] at both ends.
+///
+/// Every pixel is blended once by how much of it is covered by the line, so
+/// the end points can be anywhere inside of a pixel.  A line whose end points
+/// are the same draws nothing with [`LineCap::Butt`], a square with
+/// [`LineCap::Square`], and a dot with [`LineCap::Round`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{antialiased_line_capped, LineCap};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// antialiased_line_capped(
+///     &mut image,
+///     (10.0, 10.0),
+///     (390.5, 200.25),
+///     4.5,
+///     LineCap::Round,
+///     Rgba([255, 0, 0, 255]),
+/// );
+/// ```
+///
+/// See also: [`Draw::antialiased_line_capped`](crate::Draw::antialiased_line_capped)
+///
+pub fn antialiased_line_capped<P, T>(
+    image: &mut image::RgbaImage,
+    a: P,
+    b: P,
+    width: f32,
+    cap: LineCap,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    let a = Pt::new(a.x().into(), a.y().into());
+    let b = Pt::new(b.x().into(), b.y().into());
 
-    let dx = (x1 - x0).abs(); // x difference
-    let dy = (y1 - y0).abs(); // y difference
+    if width <= 0.0 || (a == b && cap == LineCap::Butt) {
+        return;
+    }
 
-    // amount to added to x
-    let sx = if x0 < x1 { 1 } else { -1 };
-    // amount added to y
-    let sy = if y0 < y1 { 1 } else { -1 };
+    Segment::new(a, b, f64::from(width), cap).blend(image, color);
+}
 
-    let mut err = dx - dy;
-    let ed = if dx + dy == 0 {
-        1.0
+/// Draws a dashed antialiased line that is `width` pixels wide, with the
+/// given [`LineCap`] at both ends of every dash.
+///
+/// The line is cut into dashes along its length by the [`DashPattern`],
+/// starting at `a`, so the ends of the dashes are antialiased too.  Caps
+/// reach past the ends of a dash like they do for a whole line, so dashes with
+/// a length of 0 and [`LineCap::Round`] draw a dotted line.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::{antialiased_dashed_line, DashPattern, LineCap};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // 12px dashes with 6px gaps
+/// let dashes = DashPattern::new([12, 6], 0);
+/// antialiased_dashed_line(&mut image, (10.0, 10.0), (390.5, 200.0), 3.0, dashes, LineCap::Butt, Rgba([255, 0, 0, 255]));
+///
+/// // Round dots 8px apart
+/// let dots = DashPattern::new([0, 8], 0);
+/// antialiased_dashed_line(&mut image, (10, 50), (390, 240), 4.0, dots, LineCap::Round, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_dashed_line`](crate::Draw::antialiased_dashed_line)
+///
+pub fn antialiased_dashed_line<P, T, D>(
+    image: &mut image::RgbaImage,
+    a: P,
+    b: P,
+    width: f32,
+    pattern: D,
+    cap: LineCap,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    D: Into<DashPattern>,
+{
+    let a = Pt::new(a.x().into(), a.y().into());
+    let b = Pt::new(b.x().into(), b.y().into());
+
+    if width <= 0.0 {
+        return;
+    }
+
+    let line = Segment::new(a, b, f64::from(width), cap);
+    let d = b - a;
+    let len = d.x().hypot(d.y());
+    let dir = if len > 0.0 {
+        d.div(len)
     } else {
-        ((dx as f32 * dx as f32) + (dy as f32 * dy as f32)).sqrt()
+        Pt::new(1.0, 0.0)
     };
 
-    let wd = (wd + 1.0) / 2.0;
-
-    loop {
-        {
-            let o = (((err - dx + dy).abs() as f32) / ed - wd + 1.0).max(0.0);
-            blend_at(image, x0 as u32, y0 as u32, 1.0 - o, color);
-        }
-        let mut e2 = err;
-        let mut x2 = x0;
-        if 2 * e2 >= -dx {
-            // x step
-            e2 += dy;
-            let mut y2 = y0;
-            while (e2 as f32) < ed * wd && (y1 != y2 || dx > dy) {
-                y2 += sy;
-                let o = (e2.abs() as f32 / ed - wd + 1.0).max(0.0);
-                blend_at(image, x0 as u32, y2 as u32, 1.0 - o, color);
-                e2 += dx;
-            }
-            if x0 == x1 {
-                break;
-            }
-            e2 = err;
-            err -= dy;
-            x0 += sx;
-        }
-        if 2 * e2 <= dy {
-            // y step
-            e2 = dx - e2;
-            while (e2 as f32) < (ed * wd) && (x1 != x2 || dx < dy) {
-                x2 += sx;
-                let o = (e2.abs() as f32 / ed - wd + 1.0).max(0.0);
-                blend_at(image, x2 as u32, y0 as u32, 1.0 - o, color);
-                e2 += dy;
-            }
-            if y0 == y1 {
-                break;
+    // The part of the line close enough to reach the image, as distances from
+    // `a`, so dashes far outside of the image are never looked at
+    let pad = f64::from(width) + 2.0;
+    let (mut t0, mut t1) = (0.0, len);
+    for (p, d, size) in [
+        (a.x(), dir.x(), f64::from(image.width())),
+        (a.y(), dir.y(), f64::from(image.height())),
+    ] {
+        let (lo, hi) = (-pad, size + pad);
+        if d.abs() < f64::EPSILON {
+            if p < lo || p > hi {
+                return;
             }
-            err += dx;
-            y0 += sy;
+        } else {
+            let (u0, u1) = ((lo - p) / d, (hi - p) / d);
+            t0 = f64::max(t0, u0.min(u1));
+            t1 = f64::min(t1, u0.max(u1));
         }
     }
+    if t0 > t1 {
+        return;
+    }
+
+    let mut cov = Coverage::new(image, line.bounds());
+    for (start, end) in pattern.into().dashes(t0, t1 - t0) {
+        if end <= start && cap == LineCap::Butt {
+            continue;
+        }
+
+        let (start, end) = (t0 + start, t0 + end);
+        let dash = Segment::new(a + dir.mul(start), a + dir.mul(end), f64::from(width), cap);
+        cov.add_segment(&dash);
+    }
+    cov.blend(image, color);
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{DashPattern, LineCap};
+    use crate::Pt;
+
+    #[test]
+    fn thick_line() -> Result<(), image::ImageError> {
+        let mut image = image::RgbaImage::from_pixel(400, 400, image::Rgba([255, 255, 255, 255]));
+        for (i, w) in (0..).zip([1, 2, 3, 5, 8, 10]) {
+            let y = 20 + 60 * i;
+            super::thick_line(
+                &mut image,
+                (10, y),
+                (380, y + 40),
+                w,
+                image::Rgba([255, 0, 0, 255]),
+            );
+            super::thick_line(
+                &mut image,
+                (20 + 30 * i, 390),
+                (40 + 50 * i, 30),
+                w,
+                image::Rgba([0, 0, 255, 255]),
+            );
+        }
+        image.save("images/thick_line.png")
+    }
+
+    #[test]
+    fn thick_line_one_pixel() {
+        let color = image::Luma([255]);
+        for (a, b) in [
+            ((2, 3), (37, 20)),
+            ((30, 1), (4, 38)),
+            ((5, 5), (35, 35)),
+            ((1, 9), (38, 9)),
+        ] {
+            let mut thin = image::GrayImage::new(40, 40);
+            let mut thick = image::GrayImage::new(40, 40);
+            crate::lines::line(&mut thin, a, b, color);
+            super::thick_line(&mut thick, a, b, 1, color);
+            assert_eq!(thin, thick);
+        }
+    }
+
+    #[test]
+    fn thick_line_clipped() {
+        // Lines that go past the image draw the same pixels as in a larger image
+        let color = image::Luma([255]);
+        for (a, b, width) in [
+            ((-50, -20), (90, 60), 5),
+            ((-7, 45), (47, -30), 8),
+            ((20, -90), (22, 130), 3),
+            ((-100, 38), (140, 43), 6),
+        ] {
+            let mut image = image::GrayImage::new(40, 40);
+            let mut large = image::GrayImage::new(400, 400);
+            super::thick_line(&mut image, a, b, width, color);
+            super::thick_line(
+                &mut large,
+                (a.0 + 180, a.1 + 180),
+                (b.0 + 180, b.1 + 180),
+                width,
+                color,
+            );
+            let large = image::imageops::crop_imm(&large, 180, 180, 40, 40).to_image();
+            assert_eq!(image, large);
+        }
+    }
+
+    #[test]
+    fn thick_line_width() {
+        let color = image::Luma([255]);
+
+        for (a, b, width) in [
+            ((5, 20), (35, 20), 4),
+            ((20, 5), (20, 35), 3),
+            ((5, 5), (35, 35), 4),
+            ((3, 10), (37, 27), 6),
+            ((12, 3), (25, 37), 5),
+        ] {
+            let mut image = image::GrayImage::new(40, 40);
+            super::thick_line(&mut image, a, b, width, color);
+
+            let (a, b) = (
+                Pt::new(a.0 as f64, a.1 as f64),
+                Pt::new(b.0 as f64, b.1 as f64),
+            );
+            let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+            let len = dx.hypot(dy);
+            let half = f64::from(width) / 2.0;
+
+            for (x, y, p) in image.enumerate_pixels() {
+                let (px, py) = (x as f64 - a.x(), y as f64 - a.y());
+                // Distance across the line and position along it
+                let across = (px * dy - py * dx).abs() / len;
+                let along = (px * dx + py * dy) / len;
+
+                if *p == color {
+                    assert!(across <= half + 0.75, "({x}, {y}) is too far from the line");
+                } else if (half..=len - half).contains(&along) {
+                    assert!(across > half - 0.75, "({x}, {y}) was not drawn");
+                }
+            }
+        }
+    }
+
     #[test]
     fn thick_aa_line() -> Result<(), image::ImageError> {
         let mut image = image::RgbaImage::from_pixel(400, 400, image::Rgba([255, 255, 255, 255]));
@@ -110,4 +447,259 @@ mod tests {
         );
         image.save("images/thick_aa_line.png")
     }
+
+    #[test]
+    fn thick_line_capped_butt() {
+        let color = image::Luma([255]);
+        for (a, b, width) in [((5, 20), (35, 20), 4), ((3, 10), (37, 27), 7)] {
+            let mut thick = image::GrayImage::new(40, 40);
+            let mut capped = image::GrayImage::new(40, 40);
+            super::thick_line(&mut thick, a, b, width, color);
+            super::thick_line_capped(&mut capped, a, b, width, LineCap::Butt, color);
+            assert_eq!(thick, capped);
+        }
+    }
+
+    #[test]
+    fn thick_line_capped_ends() {
+        let color = image::Luma([255]);
+        let drawn = |cap| {
+            let mut image = image::GrayImage::new(40, 40);
+            super::thick_line_capped(&mut image, (10, 20), (30, 20), 9, cap, color);
+            move |x: u32, y: u32| *image.get_pixel(x, y) == color
+        };
+
+        let butt = drawn(LineCap::Butt);
+        assert!(!butt(34, 20) && !butt(6, 20));
+        assert!(!butt(9, 20) && !butt(31, 20));
+
+        let square = drawn(LineCap::Square);
+        assert!(square(34, 20) && square(6, 20));
+        assert!(square(34, 24) && square(6, 16));
+        assert!(!square(35, 20) && !square(5, 20));
+
+        let round = drawn(LineCap::Round);
+        assert!(round(34, 20) && round(6, 20));
+        assert!(!round(34, 24) && !round(6, 16));
+        assert!(!round(35, 20) && !round(5, 20));
+    }
+
+    #[test]
+    fn aa_line_capped() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let red = image::Rgba([255, 0, 0, 255]);
+        for (i, cap) in (0..).zip([LineCap::Butt, LineCap::Square, LineCap::Round]) {
+            let x = 50.0 + 120.0 * f64::from(i);
+            super::antialiased_line_capped(
+                &mut image,
+                (x, 30.0),
+                (x + 60.0, 370.5),
+                12.0,
+                cap,
+                red,
+            );
+            super::antialiased_line_capped(&mut image, (x, 200.0), (x + 0.0, 200.0), 9.0, cap, red);
+        }
+        image.save("images/aa_line_capped.png")
+    }
+
+    #[test]
+    fn aa_line_capped_ends() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let white = image::Rgba([255, 255, 255, 255]);
+        let draw = |cap| {
+            let mut image = crate::test::img::blank((40, 40));
+            super::antialiased_line_capped(&mut image, (10, 20), (30, 20), 9.0, cap, red);
+            image
+        };
+
+        let butt = draw(LineCap::Butt);
+        assert_eq!(*butt.get_pixel(20, 16), red);
+        assert_eq!(*butt.get_pixel(11, 20), red);
+        assert_ne!(*butt.get_pixel(10, 20), red);
+        assert_eq!(*butt.get_pixel(9, 20), white);
+        assert_eq!(*butt.get_pixel(20, 25), white);
+
+        let square = draw(LineCap::Square);
+        assert_eq!(*square.get_pixel(6, 16), red);
+        assert_eq!(*square.get_pixel(34, 24), red);
+        assert_eq!(*square.get_pixel(35, 20), white);
+
+        let round = draw(LineCap::Round);
+        assert_eq!(*round.get_pixel(6, 20), red);
+        assert_eq!(*round.get_pixel(34, 20), red);
+        assert_eq!(*round.get_pixel(35, 20), white);
+        assert_ne!(*round.get_pixel(34, 24), red);
+    }
+
+    #[test]
+    fn aa_line_float() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let mut line = crate::test::img::blank((40, 40));
+        let mut capped = crate::test::img::blank((40, 40));
+        super::antialiased_line(&mut line, (3.5, 4.0), (35.0, 30.25), 3.0, red);
+        super::antialiased_line_capped(
+            &mut capped,
+            (3.5, 4.0),
+            (35.0, 30.25),
+            3.0,
+            LineCap::Butt,
+            red,
+        );
+        assert_eq!(line, capped);
+
+        // Moving an end point a fraction of a pixel changes the line
+        let mut moved = crate::test::img::blank((40, 40));
+        super::antialiased_line(&mut moved, (3.75, 4.0), (35.0, 30.25), 3.0, red);
+        assert_ne!(line, moved);
+    }
+
+    #[test]
+    fn aa_line_off_image() {
+        // Only the part of the line's bounds inside of the image is visited
+        let red = image::Rgba([255, 0, 0, 255]);
+        let mut image = crate::test::img::blank((40, 40));
+        let blank = image.clone();
+        super::antialiased_line(&mut image, (-1e6, -50.0), (1e6, -10.0), 3.0, red);
+        super::antialiased_line(&mut image, (45.0, -1e6), (80.0, 1e6), 3.0, red);
+        assert_eq!(image, blank);
+    }
+
+    #[test]
+    fn aa_line_no_snapping() {
+        // Whole number end points are drawn the same way as any others, so
+        // crossing one barely changes the line
+        let red = image::Rgba([255, 0, 0, 255]);
+        let mut whole = crate::test::img::blank((40, 40));
+        let mut near = crate::test::img::blank((40, 40));
+        super::antialiased_line(&mut whole, (3, 4), (35, 30), 3.0, red);
+        super::antialiased_line(&mut near, (2.999, 4.0), (35.0, 30.0), 3.0, red);
+
+        for (a, b) in whole.pixels().zip(near.pixels()) {
+            for (ca, cb) in a.0.iter().zip(b.0) {
+                assert!((i32::from(*ca) - i32::from(cb)).abs() <= 2, "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn aa_dashed_line() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let red = image::Rgba([255, 0, 0, 255]);
+        let dashes = DashPattern::new([16, 8], 0);
+        let dots = DashPattern::new([0, 12], 0);
+        for (i, cap) in (0..).zip([LineCap::Butt, LineCap::Square, LineCap::Round]) {
+            let y = 30.0 + 120.0 * f64::from(i);
+            super::antialiased_dashed_line(
+                &mut image,
+                (10.0, y),
+                (390.0, y + 40.5),
+                6.0,
+                dashes.clone(),
+                cap,
+                red,
+            );
+            super::antialiased_dashed_line(
+                &mut image,
+                (10.0, y + 60.0),
+                (390.0, y + 60.0),
+                5.0,
+                dots.clone(),
+                cap,
+                red,
+            );
+        }
+        image.save("images/aa_dashed_line.png")
+    }
+
+    #[test]
+    fn aa_dashed_line_dashes() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let white = image::Rgba([255, 255, 255, 255]);
+        let draw = |pattern: DashPattern, cap| {
+            let mut image = crate::test::img::blank((60, 20));
+            super::antialiased_dashed_line(&mut image, (5, 10), (55, 10), 4.0, pattern, cap, red);
+            image
+        };
+
+        // Dashes from 0 to 10, 20 to 30, and 40 to 50 along the line
+        let butt = draw(DashPattern::new([10, 10], 0), LineCap::Butt);
+        for x in [6, 14, 26, 34, 46, 54] {
+            assert_eq!(*butt.get_pixel(x, 10), red, "{x}");
+        }
+        for x in [16, 24, 36, 44] {
+            assert_eq!(*butt.get_pixel(x, 10), white, "{x}");
+        }
+        // Dash ends are antialiased
+        for x in [15, 25, 35, 45] {
+            let p = butt.get_pixel(x, 10);
+            assert!(*p != red && *p != white, "{x}");
+        }
+
+        // Round dots every 10 pixels
+        let dots = draw(DashPattern::new([0, 10], 0), LineCap::Round);
+        for x in [5, 15, 25, 35, 45, 55] {
+            assert_eq!(*dots.get_pixel(x, 10), red, "{x}");
+            assert_eq!(*dots.get_pixel(x + 4, 10), white, "{x}");
+        }
+
+        // Zero length dashes with butt caps draw nothing
+        let nothing = draw(DashPattern::new([0, 10], 0), LineCap::Butt);
+        assert!(nothing.pixels().all(|p| *p == white));
+    }
+
+    #[test]
+    fn aa_dashed_line_clipped() {
+        // Lines that go past the image draw the same pixels as in a larger image
+        let red = image::Rgba([255, 0, 0, 255]);
+        for (a, b, cap) in [
+            ((-50.5, -20.0), (90.0, 60.25), LineCap::Butt),
+            ((-7.0, 45.0), (47.0, -30.5), LineCap::Round),
+            ((20.0, -90.0), (20.0, 130.0), LineCap::Square),
+            ((-100.25, 38.0), (140.0, 38.0), LineCap::Round),
+        ] {
+            let pattern = DashPattern::new([7, 4], 3);
+            let mut image = crate::test::img::blank((40, 40));
+            let mut large = crate::test::img::blank((400, 400));
+            super::antialiased_dashed_line(&mut image, a, b, 3.5, pattern.clone(), cap, red);
+            super::antialiased_dashed_line(
+                &mut large,
+                (a.0 + 180.0, a.1 + 180.0),
+                (b.0 + 180.0, b.1 + 180.0),
+                3.5,
+                pattern,
+                cap,
+                red,
+            );
+            let large = image::imageops::crop_imm(&large, 180, 180, 40, 40).to_image();
+            assert_eq!(image, large);
+        }
+
+        // Lines far outside of the image do not step through their dashes
+        let mut image = crate::test::img::blank((40, 40));
+        let blank = image.clone();
+        let dots = DashPattern::new([0, 1], 0);
+        super::antialiased_dashed_line(
+            &mut image,
+            (-1e12, -5.0),
+            (1e12, -5.0),
+            2.0,
+            dots.clone(),
+            LineCap::Round,
+            red,
+        );
+        assert_eq!(image, blank);
+
+        // Only the dashes near the image are added
+        super::antialiased_dashed_line(
+            &mut image,
+            (-1e12, 20.0),
+            (1e12, 20.0),
+            2.0,
+            dots,
+            LineCap::Round,
+            red,
+        );
+        assert_eq!(*image.get_pixel(10, 20), red);
+    }
 }
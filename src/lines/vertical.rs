@@ -2,6 +2,7 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::many_single_char_names)]
 
+use super::DashPattern;
 use crate::ops::blend_at_unchecked;
 use crate::pt::Point;
 use image::{GenericImage, Rgba, RgbaImage};
@@ -35,7 +36,9 @@ where
 
 /// Draws a dashed vertical line.
 ///
-/// A `width` of 0 will draw a solid vertical line.
+/// The [`DashPattern`] starts at `pt`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid vertical line.
 ///
 /// # Example
 ///
@@ -51,37 +54,34 @@ where
 /// /// Vertical dashed line across the center of the image with a 2px dash
 /// vertical_dashed_line(&mut image, (200, 0), 399, 2, color);
 /// ```
-pub fn vertical_dashed_line<I, P>(image: &mut I, pt: P, mut y2: u32, width: u32, color: I::Pixel)
+pub fn vertical_dashed_line<I, P, D>(image: &mut I, pt: P, y2: u32, pattern: D, color: I::Pixel)
 where
     I: GenericImage,
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         crate::lines::vertical_line(image, pt, y2, color);
         return;
     }
 
-    let (x, mut y0) = pt.tuple();
-
-    if y0 > y2 {
-        std::mem::swap(&mut y0, &mut y2);
-    }
+    let (x, y0) = pt.tuple();
+    let (y1, y2) = (y0.min(y2), y0.max(y2));
 
-    if x >= image.width() || (y0 >= image.height()) {
+    if x >= image.width() || y1 >= image.height() {
         return;
     }
 
-    let y1 = y2.min(image.height() - 1);
-    let mut y = y0.min(image.height() - 1);
-    let mut i = 0;
-
-    while y <= y1 {
+    for y in y1..=y2.min(image.height() - 1) {
+        if !pattern.is_on(u64::from(y.abs_diff(y0))) {
+            continue;
+        }
         // This is safe due to the min calls above
         unsafe {
             image.unsafe_put_pixel(x, y, color);
         }
-        y = if i == width - 1 { y + width + 1 } else { y + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -123,7 +123,9 @@ where
 ///
 /// Opacity should be in the range `0..=1`.
 ///
-/// A `width` of 0 will draw a solid vertical line.
+/// The [`DashPattern`] starts at `pt`, whichever way the line goes.  A
+/// single number `n` can be used for `n` pixel dashes with `n` pixel gaps,
+/// and a width of 0 will draw a solid vertical line.
 ///
 /// # Panics
 ///
@@ -142,44 +144,42 @@ where
 /// /// Vertical dashed line across the center of the image with a 2px dash and 50% opacity
 /// vertical_dashed_line_alpha(&mut image, (200, 0), 399, 2, 0.5, color);
 /// ```
-pub fn vertical_dashed_line_alpha<P>(
+pub fn vertical_dashed_line_alpha<P, D>(
     image: &mut RgbaImage,
     pt: P,
-    mut y2: u32,
-    width: u32,
+    y2: u32,
+    pattern: D,
     opacity: f32,
     color: Rgba<u8>,
 ) where
     P: Point<u32>,
+    D: Into<DashPattern>,
 {
     check_opacity!(opacity);
 
-    if width == 0 {
+    let pattern = pattern.into();
+
+    if pattern.is_solid() {
         vertical_line_alpha(image, pt, y2, opacity, color);
         return;
     }
 
-    let (x, mut y1) = pt.tuple();
+    let (x, y0) = pt.tuple();
+    let (y1, y2) = (y0.min(y2), y0.max(y2));
 
-    if y1 > y2 {
-        std::mem::swap(&mut y1, &mut y2);
-    }
-
-    if x >= image.width() || (y1 >= image.height()) {
+    if x >= image.width() || y1 >= image.height() {
         return;
     }
 
-    let y2 = y2.min(image.height() - 1);
-    let mut y = y1.min(image.height() - 1);
-    let mut i = 0;
-    while y <= y2 {
+    for y in y1..=y2.min(image.height() - 1) {
+        if !pattern.is_on(u64::from(y.abs_diff(y0))) {
+            continue;
+        }
         let (r, g, b) = (color[0], color[1], color[2]);
         // This is safe due to the min calls above
         unsafe {
             blend_at_unchecked(image, x, y, opacity, Rgba([r, g, b, 255]));
         }
-        y = if i == width - 1 { y + width + 1 } else { y + 1 };
-        i = if i == width - 1 { 0 } else { i + 1 };
     }
 }
 
@@ -206,6 +206,13 @@ mod tests {
 
     mod vertical_dashed_line {
 
+        test_pixels_changed!(
+            vertical_dashed_line_reversed,
+            vertical_dashed_line((0, 9), 0, crate::lines::DashPattern::new([3, 1, 1, 1], 2)),
+            10,
+            &*vec![(0, 9), (0, 7), (0, 5), (0, 4), (0, 3), (0, 1)]
+        );
+
         test_pixels_changed!(
             vertical_dashed_line_0px_width,
             vertical_dashed_line((0, 0), 10, 0),
@@ -0,0 +1,344 @@
+use crate::antialias::AAPt;
+use crate::{Point, Pt};
+
+/// An iterator over the antialiased points of a 1px wide line, using Xiaolin
+/// Wu's algorithm.
+///
+/// The end points can be anywhere inside of a pixel.  Every step along the
+/// line's major axis splits the coverage between the two pixels straddling
+/// the exact line, and the pixels at each end are faded by how much of them
+/// the line reaches.
+///
+/// # Example
+///
+/// ```
+/// use freehand::lines::WuLine;
+/// use freehand::Pt;
+///
+/// for pt in WuLine::new(Pt::new(0.5, 0.25), Pt::new(399.0, 210.75)) {
+///     // pt.a and pt.b are the two pixels, pt.oa and pt.ob are their opacities
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct WuLine {
+    /// Current position along the major axis
+    u: i32,
+    /// Last position along the major axis
+    end: i32,
+    /// Whether the last point has been returned
+    done: bool,
+    /// Start point, with the major axis as x
+    a: Pt<f64>,
+    /// End point along the major axis
+    stop: f64,
+    /// Change along the minor axis for every step along the major axis
+    gradient: f64,
+    /// If steep the x, y coordinates are transposed
+    steep: bool,
+}
+
+impl WuLine {
+    /// Creates an iterator over the antialiased points between two points.
+    pub fn new<P, T>(a: P, b: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let mut a = Pt::new(a.x().into(), a.y().into());
+        let mut b = Pt::new(b.x().into(), b.y().into());
+
+        let steep = (a.x() - b.x()).abs() < (a.y() - b.y()).abs();
+        if steep {
+            a.swap();
+            b.swap();
+        }
+
+        if a.x() > b.x() {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        let dx = b.x() - a.x();
+        let gradient = if dx == 0.0 { 0.0 } else { (b.y() - a.y()) / dx };
+
+        Self {
+            u: a.x().round() as i32,
+            end: b.x().round() as i32,
+            done: false,
+            a,
+            stop: b.x(),
+            gradient,
+            steep,
+        }
+    }
+
+    /// Skips the points of the line that are outside of the rectangle from
+    /// `min` to `max` (inclusive), without stepping through them.
+    ///
+    /// The points that are left are the same as without clipping, except that
+    /// points whose pixels are both outside of the rectangle may be left out.
+    /// Returns the number of points skipped at the start of the line.  If no
+    /// points are inside of the rectangle the iterator will be empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use freehand::lines::WuLine;
+    /// use freehand::Pt;
+    ///
+    /// let mut line = WuLine::new(Pt::new(-1e6, 0.0), Pt::new(1e6, 400.0));
+    /// line.clip((0, 0), (399, 399));
+    /// assert!(line.count() <= 400);
+    /// ```
+    pub fn clip<P>(&mut self, min: P, max: P) -> u64
+    where
+        P: Point<i32>,
+    {
+        let (mut min, mut max) = (min.pt(), max.pt());
+        if self.steep {
+            min.swap();
+            max.swap();
+        }
+
+        let start = f64::from(self.u);
+        let mut lo = start.max(f64::from(min.x()));
+        let mut hi = f64::from(self.end).min(f64::from(max.x()));
+
+        // Either pixel is inside when the minor axis position is in this range
+        let (v_lo, v_hi) = (f64::from(min.y()) - 1.0, f64::from(max.y()) + 1.0);
+        if self.gradient == 0.0 {
+            if !(v_lo..v_hi).contains(&self.a.y()) {
+                hi = lo - 1.0;
+            }
+        } else {
+            let at = |v: f64| self.a.x() + (v - self.a.y()) / self.gradient;
+            let (u0, u1) = (at(v_lo), at(v_hi));
+            lo = lo.max(u0.min(u1).floor());
+            hi = hi.min(u0.max(u1).ceil());
+        }
+
+        let skipped = (lo - start).max(0.0) as u64;
+        if lo > hi {
+            // Nothing left to iterate over
+            self.done = true;
+            return skipped;
+        }
+
+        self.u = lo as i32;
+        self.end = hi as i32;
+        skipped
+    }
+}
+
+impl Iterator for WuLine {
+    type Item = AAPt<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // Stop on the last point instead of stepping past it, which could
+        // overflow at the edge of the i32 range
+        let u = self.u;
+        if u >= self.end {
+            self.done = true;
+        } else {
+            self.u += 1;
+        }
+
+        let v = self.a.y() + self.gradient * (f64::from(u) - self.a.x());
+        let fl = v.floor();
+        let mut a = Pt::new(u, fl as i32);
+        let mut b = Pt::new(u, (fl + 1.0) as i32);
+        if self.steep {
+            a.swap();
+            b.swap();
+        }
+
+        // How much of the pixel's width along the major axis the line covers
+        let gap = ((f64::from(u) + 0.5).min(self.stop) - (f64::from(u) - 0.5).max(self.a.x()))
+            .clamp(0.0, 1.0);
+
+        Some(AAPt::new(a, b, v - fl).mult_opac(gap))
+    }
+}
+
+/// Draws a 1px wide antialiased line between two points using Xiaolin Wu's
+/// algorithm.
+///
+/// The end points do not have to be whole pixels, so lines that move slowly
+/// do not jump from one pixel to the next.  See [`WuLine`], and
+/// [`antialiased_line`](super::antialiased_line) for thicker lines.
+///
+/// # Panics
+///
+/// Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::lines::wu_line;
+/// use freehand::Pt;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// wu_line(&mut image, Pt::new(10.25, 10.0), Pt::new(390.0, 200.5), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::wu_line`](crate::Draw::wu_line)
+///
+pub fn wu_line<P, T>(image: &mut image::RgbaImage, a: P, b: P, color: image::Rgba<u8>)
+where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    check_img_i32!(image);
+
+    #[allow(clippy::cast_possible_wrap)]
+    let width = image.width() as i32;
+    #[allow(clippy::cast_possible_wrap)]
+    let height = image.height() as i32;
+
+    let mut line = WuLine::new(a, b);
+    line.clip((0, 0), (width - 1, height - 1));
+    for pt in line {
+        pt.draw(image, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WuLine;
+    use crate::Pt;
+
+    #[test]
+    fn wu_line() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        for i in 0..10 {
+            let o = f64::from(i) / 10.0;
+            super::wu_line(
+                &mut image,
+                Pt::new(10.0 + o, 20.0 + 30.0 * f64::from(i) + o),
+                Pt::new(390.0, 60.0 + 35.0 * f64::from(i)),
+                image::Rgba([255, 0, 0, 255]),
+            );
+            super::wu_line(
+                &mut image,
+                Pt::new(30.0 + 35.0 * f64::from(i) + o, 390.0),
+                Pt::new(20.0 + 38.0 * f64::from(i), 380.0 - 300.0 * o),
+                image::Rgba([0, 0, 255, 255]),
+            );
+        }
+        image.save("images/wu_line.png")
+    }
+
+    #[test]
+    fn wu_line_coverage() {
+        // Every column of a shallow line is covered by exactly one pixel
+        for (a, b) in [
+            (Pt::new(2.0_f64, 3.25), Pt::new(37.0, 20.5)),
+            (Pt::new(37.5, 30.0), Pt::new(4.0, 12.75)),
+            (Pt::new(3.0, 5.5), Pt::new(30.0, 32.5)),
+        ] {
+            let pts: Vec<_> = WuLine::new(a, b).collect();
+            assert_eq!(
+                pts.len(),
+                1 + (b.x().round() - a.x().round()).abs() as usize
+            );
+
+            for pt in &pts[1..pts.len() - 1] {
+                assert!((pt.oa + pt.ob - 1.0).abs() < 1e-9);
+                assert_eq!(pt.a.x(), pt.b.x());
+                assert_eq!(pt.a.y() + 1, pt.b.y());
+
+                // The exact line goes through the weighted center of the pixels
+                let t = (f64::from(pt.a.x()) - a.x()) / (b.x() - a.x());
+                let y = a.y() + t * (b.y() - a.y());
+                assert!((f64::from(pt.a.y()) + pt.ob - y).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn wu_line_clipped() {
+        // Clipping leaves the points that touch the rectangle
+        for (a, b) in [
+            (Pt::new(-20.5_f64, 3.25), Pt::new(57.0, 30.5)),
+            (Pt::new(37.5, -30.0), Pt::new(4.0, 62.75)),
+            (Pt::new(-5.0, 15.5), Pt::new(60.0, 15.5)),
+            (Pt::new(-5.0, -15.5), Pt::new(60.0, -15.5)),
+        ] {
+            let touches = |pt: &crate::antialias::AAPt<i32>| {
+                [pt.a, pt.b]
+                    .iter()
+                    .any(|p| (0..40).contains(&p.x()) && (0..40).contains(&p.y()))
+            };
+            let key = |pt: crate::antialias::AAPt<i32>| (pt.a, pt.b, pt.oa, pt.ob);
+            let all: Vec<_> = WuLine::new(a, b).filter(touches).map(key).collect();
+            let mut line = WuLine::new(a, b);
+            line.clip((0, 0), (39, 39));
+            let clipped: Vec<_> = line.filter(touches).map(key).collect();
+            assert_eq!(all, clipped);
+        }
+
+        // Lines far outside of the image take no steps
+        let mut line = WuLine::new(Pt::new(-1e6, 0.0), Pt::new(1e6, 400.0));
+        line.clip((0, 0), (399, 399));
+        assert!(line.count() <= 402);
+
+        let mut line = WuLine::new(Pt::new(-1e6, -50.0), Pt::new(1e6, -10.0));
+        line.clip((0, 0), (399, 399));
+        assert_eq!(line.count(), 0);
+
+        let mut line = WuLine::new(Pt::new(-1e10, -50.0), Pt::new(-5e9, -10.0));
+        line.clip((0, 0), (399, 399));
+        assert_eq!(line.count(), 0);
+    }
+
+    #[test]
+    fn wu_line_i32_limits() {
+        // Points past the i32 range stop at its edge without overflowing
+        let pts: Vec<_> = WuLine::new(Pt::new(3e9, 0.0), Pt::new(3e9 + 1.0, 0.5)).collect();
+        assert_eq!(pts.len(), 1);
+        assert_eq!(pts[0].a.x(), i32::MAX);
+
+        let pts: Vec<_> = WuLine::new(Pt::new(0.0, 3e9), Pt::new(5.0, 3e9)).collect();
+        assert_eq!(pts.len(), 6);
+        assert!(pts.iter().all(|pt| pt.b.y() == i32::MAX));
+    }
+
+    #[test]
+    fn wu_line_off_image() {
+        let mut image = crate::test::img::blank((40, 40));
+        let blank = image.clone();
+        let red = image::Rgba([255, 0, 0, 255]);
+        super::wu_line(&mut image, Pt::new(-1e6, -50.0), Pt::new(1e6, -10.0), red);
+        super::wu_line(&mut image, Pt::new(45.0, -1e6), Pt::new(80.0, 1e6), red);
+        // End points past the i32 range
+        super::wu_line(&mut image, Pt::new(-1e10, -50.0), Pt::new(-5e9, -10.0), red);
+        super::wu_line(&mut image, Pt::new(-1e10, 60.0), Pt::new(1e10, 80.0), red);
+        assert_eq!(image, blank);
+    }
+
+    #[test]
+    fn wu_line_ends() {
+        // Ends on a pixel's center cover half of it
+        let pts: Vec<_> = WuLine::new((10, 10), (20, 10)).collect();
+        assert_eq!(pts.len(), 11);
+        assert!((pts[0].oa - 0.5).abs() < 1e-9);
+        assert!((pts[10].oa - 0.5).abs() < 1e-9);
+        assert!((pts[5].oa - 1.0).abs() < 1e-9);
+
+        let pts: Vec<_> = WuLine::new(Pt::new(9.75, 10.0), Pt::new(20.25, 10.0)).collect();
+        assert!((pts[0].oa - 0.75).abs() < 1e-9);
+        assert!((pts[10].oa - 0.75).abs() < 1e-9);
+
+        // A steep line steps along y
+        let pts: Vec<_> = WuLine::new(Pt::new(5.5, 2.0), Pt::new(6.5, 30.0)).collect();
+        assert_eq!(pts.len(), 29);
+        assert!(pts
+            .iter()
+            .all(|pt| pt.a.y() == pt.b.y() && pt.a.x() + 1 == pt.b.x()));
+    }
+}
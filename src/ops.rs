@@ -1,5 +1,7 @@
 //! Helper functions for image operations
 
 mod blend;
+mod once;
 
 pub use blend::{blend_at, blend_at_unchecked};
+pub(crate) use once::BlendOnce;
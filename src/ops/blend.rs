@@ -143,7 +143,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Opacity must be between")]
     fn safe_blend_invalids() {
         let color = image::Rgba([255, 0, 0, 255]);
         let mut image = image::RgbaImage::from_pixel(1, 1, image::Rgba([255, 255, 255, 255]));
@@ -0,0 +1,99 @@
+use super::blend_at_unchecked;
+use crate::Pt;
+
+/// Blends pixels into an image while skipping pixels that have already been
+/// blended.
+///
+/// Shapes that are drawn in overlapping pieces (like the octants of a circle)
+/// use this so the pixels the pieces share are not blended twice.
+pub(crate) struct BlendOnce<'i> {
+    image: &'i mut image::RgbaImage,
+    /// Top left corner of the area that can be blended
+    min: Pt<i64>,
+    /// Width of the area that can be blended
+    width: i64,
+    /// Height of the area that can be blended
+    height: i64,
+    /// Pixels in the area that have been blended, row by row
+    blended: Vec<bool>,
+    opacity: f32,
+    color: image::Rgba<u8>,
+}
+
+impl<'i> BlendOnce<'i> {
+    /// Creates a [`BlendOnce`] for a shape that fits in a circle with a given
+    /// radius and center.  Pixels outside of the circle's bounding box or the
+    /// image are ignored.
+    pub(crate) fn around(
+        image: &'i mut image::RgbaImage,
+        r: f64,
+        c: Pt<f64>,
+        opacity: f32,
+        color: image::Rgba<u8>,
+    ) -> Self {
+        let min = Pt::new(c.x() - r, c.y() - r);
+        let max = Pt::new(c.x() + r, c.y() + r);
+        Self::within(image, (min, max), opacity, color)
+    }
+
+    /// Creates a [`BlendOnce`] for a shape that fits between `min` and `max`.
+    /// Pixels outside of that area or the image are ignored.
+    pub(crate) fn within(
+        image: &'i mut image::RgbaImage,
+        (min, max): (Pt<f64>, Pt<f64>),
+        opacity: f32,
+        color: image::Rgba<u8>,
+    ) -> Self {
+        let x0 = (min.x().floor() as i64 - 1).max(0);
+        let y0 = (min.y().floor() as i64 - 1).max(0);
+        let x1 = (max.x().ceil() as i64 + 1).min(image.width() as i64 - 1);
+        let y1 = (max.y().ceil() as i64 + 1).min(image.height() as i64 - 1);
+
+        let width = (x1 - x0 + 1).max(0);
+        let height = (y1 - y0 + 1).max(0);
+
+        Self {
+            image,
+            min: Pt::new(x0, y0),
+            width,
+            height,
+            blended: vec![false; (width * height) as usize],
+            opacity,
+            color,
+        }
+    }
+
+    /// Blends a pixel unless it has already been blended.
+    pub(crate) fn blend(&mut self, pt: Pt<i32>) {
+        let x = pt.x() as i64 - self.min.x();
+        let y = pt.y() as i64 - self.min.y();
+
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            return;
+        }
+
+        let i = (y * self.width + x) as usize;
+        if !self.blended[i] {
+            self.blended[i] = true;
+            // The area is clamped to the image when created
+            unsafe {
+                blend_at_unchecked(
+                    self.image,
+                    pt.x() as u32,
+                    pt.y() as u32,
+                    self.opacity,
+                    self.color,
+                );
+            }
+        }
+    }
+
+    /// Blends a row or column of pixels from `a` to `b` (inclusive).
+    pub(crate) fn span(&mut self, (a, b): (Pt<i32>, Pt<i32>)) {
+        for y in a.y()..=b.y() {
+            for x in a.x()..=b.x() {
+                self.blend(Pt::new(x, y));
+            }
+        }
+    }
+}
@@ -416,7 +416,7 @@ impl Pt<u32> {
     ///
     /// Panics if the values cannot fit into an i32
     pub const fn i32(&self) -> Pt<i32> {
-        assert!(self.x <= std::i32::MAX as u32 && self.y <= std::i32::MAX as u32);
+        assert!(self.x <= i32::MAX as u32 && self.y <= i32::MAX as u32);
         Pt {
             x: self.x as i32,
             y: self.y as i32,
@@ -476,7 +476,7 @@ impl TryFrom<Pt<u32>> for Pt<i32> {
     type Error = &'static str;
 
     fn try_from(pt: Pt<u32>) -> Result<Self, Self::Error> {
-        if pt.x <= std::i32::MAX as u32 && pt.y <= std::i32::MAX as u32 {
+        if i32::try_from(pt.x).is_ok() && i32::try_from(pt.y).is_ok() {
             // safe because of the if check
             #[allow(clippy::cast_possible_wrap)]
             Ok(Self {
@@ -585,7 +585,7 @@ impl std::convert::TryFrom<Pt<i32>> for Pt<u32> {
 //     type Error = &'static str;
 
 //     fn try_from(pt: Pt<u32>) -> Result<Self, Self::Error> {
-//         if pt.x() > std::i32::MAX as u32 || pt.y() > std::i32::MAX as u32 {
+//         if pt.x() > i32::MAX as u32 || pt.y() > i32::MAX as u32 {
 //             Err("")
 //         } else {
 //             Ok(Pt::new(pt.x() as u32, pt.y() as u32))
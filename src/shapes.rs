@@ -1,5 +1,16 @@
-//! Various drawing functions for shapes
+//! Various drawing functions for shapes: rectangles, triangles, polygons, and paths made of lines, curves
+//! and arcs
 
+mod aa_polygon;
+mod path;
+mod polygon;
 mod rect;
+mod svg;
+mod triangle;
 
+pub use aa_polygon::antialiased_polygon_filled;
+pub use path::Path;
+pub use polygon::{polygon, polygon_alpha, polygon_filled, polygon_filled_alpha, FillRule};
 pub use rect::{rectangle, rectangle_alpha, rectangle_filled, rectangle_filled_alpha};
+pub use svg::{PathParseError, PathParseErrorKind};
+pub use triangle::{antialiased_triangle_filled, gouraud_triangle, triangle, triangle_filled};
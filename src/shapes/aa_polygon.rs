@@ -0,0 +1,374 @@
+use super::FillRule;
+use crate::{Point, Pt};
+
+/// Adds up how much of each pixel the edges of a shape cover.
+///
+/// Each edge adds the signed area between itself and the right side of the
+/// image to the cells it passes through, spread out so that a running sum
+/// along a row gives the winding number of every pixel, blended at the edges
+/// by how much of the pixel is inside.  This is how font rasterizers find
+/// exact coverage without sampling.
+struct Accumulator {
+    /// Width of the area in pixels
+    width: usize,
+    /// Height of the area in pixels
+    height: usize,
+    /// Each row has two more cells than the area is wide, for the parts of
+    /// edges on the right side of the area
+    cells: Vec<f32>,
+}
+
+impl Accumulator {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![0.0; (width + 2) * height],
+        }
+    }
+
+    /// Adds an edge, with pixel `(x, y)` covering the area from `(x, y)` to
+    /// `(x + 1, y + 1)`.  Parts of the edge above or below the area are
+    /// dropped and parts to the left or right are moved onto the border.
+    fn edge(&mut self, a: Pt<f64>, b: Pt<f64>) {
+        let (dir, top, bottom) = match a.y().partial_cmp(&b.y()) {
+            Some(std::cmp::Ordering::Less) => (1.0, a, b),
+            Some(std::cmp::Ordering::Greater) => (-1.0, b, a),
+            _ => return,
+        };
+        let height = self.height as f64;
+        if bottom.y() <= 0.0 || top.y() >= height {
+            return;
+        }
+        let at_y =
+            |y: f64| top.x() + (y - top.y()) * (bottom.x() - top.x()) / (bottom.y() - top.y());
+        let top = if top.y() < 0.0 {
+            Pt::new(at_y(0.0), 0.0)
+        } else {
+            top
+        };
+        let bottom = if bottom.y() > height {
+            Pt::new(at_y(height), height)
+        } else {
+            bottom
+        };
+
+        // Split the edge where it crosses the left and right borders
+        let width = self.width as f64;
+        let mut ts = vec![0.0, 1.0];
+        for border in [0.0, width] {
+            let t = (border - top.x()) / (bottom.x() - top.x());
+            if t > 0.0 && t < 1.0 {
+                ts.push(t);
+            }
+        }
+        ts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let lerp = |t: f64| {
+            let x = top.x() + (bottom.x() - top.x()) * t;
+            Pt::new(x.max(0.0).min(width), top.y() + (bottom.y() - top.y()) * t)
+        };
+        for w in ts.windows(2) {
+            self.line(lerp(w[0]), lerp(w[1]), dir);
+        }
+    }
+
+    /// Adds a line inside of the area going down from `top` to `bottom`,
+    /// with `dir` being 1 if the edge goes down and -1 if it goes up.
+    fn line(&mut self, top: Pt<f64>, bottom: Pt<f64>, dir: f32) {
+        if top.y() >= bottom.y() {
+            return;
+        }
+        let stride = self.width + 2;
+        let dxdy = (bottom.x() - top.x()) / (bottom.y() - top.y());
+
+        let mut x = top.x();
+        let rows = top.y().floor() as usize..(bottom.y().ceil() as usize).min(self.height);
+        for y in rows {
+            let row = &mut self.cells[y * stride..(y + 1) * stride];
+            // How far down the pixel the line goes, times the direction
+            let dy = (y as f64 + 1.0).min(bottom.y()) - (y as f64).max(top.y());
+            let next = x + dxdy * dy;
+            let d = dy as f32 * dir;
+
+            let (x0, x1) = if x < next { (x, next) } else { (next, x) };
+            let (x0_floor, x1_ceil) = (x0.floor(), x1.ceil());
+            let (x0i, x1i) = (x0_floor as usize, x1_ceil as usize);
+
+            if x1i <= x0i + 1 {
+                // The line stays inside of one pixel
+                let mid = (0.5 * (x + next) - x0_floor) as f32;
+                row[x0i] += d - d * mid;
+                row[x0i + 1] += d * mid;
+            } else {
+                let s = (x1 - x0).recip() as f32;
+                let x0f = (x0 - x0_floor) as f32;
+                let x1f = (x1 - x1_ceil + 1.0) as f32;
+                // Area of the triangles cut off in the first and last pixels
+                let first = 0.5 * s * (1.0 - x0f) * (1.0 - x0f);
+                let last = 0.5 * s * x1f * x1f;
+
+                row[x0i] += d * first;
+                if x1i == x0i + 2 {
+                    row[x0i + 1] += d * (1.0 - first - last);
+                } else {
+                    let second = s * (1.5 - x0f);
+                    row[x0i + 1] += d * (second - first);
+                    for cell in &mut row[x0i + 2..x1i - 1] {
+                        *cell += d * s;
+                    }
+                    let before_last = second + (x1i - x0i - 3) as f32 * s;
+                    row[x1i - 1] += d * (1.0 - before_last - last);
+                }
+                row[x1i] += d * last;
+            }
+            x = next;
+        }
+    }
+
+    /// Calls `f` with each pixel that is at least partly covered and how much
+    /// of it is covered, from 0.0 to 1.0.
+    fn coverage<F>(&self, rule: FillRule, mut f: F)
+    where
+        F: FnMut(usize, usize, f32),
+    {
+        let stride = self.width + 2;
+        for (y, row) in self.cells.chunks(stride).enumerate() {
+            let mut winding = 0.0;
+            for (x, cell) in row[..self.width].iter().enumerate() {
+                winding += cell;
+                let coverage = match rule {
+                    FillRule::NonZero => winding.abs().min(1.0),
+                    FillRule::EvenOdd => {
+                        let c = winding.abs() % 2.0;
+                        if c > 1.0 {
+                            2.0 - c
+                        } else {
+                            c
+                        }
+                    }
+                };
+                // Skips pixels that would not change when rounded to a u8
+                if coverage >= 1.0 / 512.0 {
+                    f(x, y, coverage.min(1.0));
+                }
+            }
+        }
+    }
+}
+
+/// Finds how much of each pixel of a `width` by `height` image is inside of
+/// the polygons, and calls `f` with each pixel that is at least partly
+/// covered and its coverage from 0.0 to 1.0.
+///
+/// Pixel centers are at whole numbers, so pixel `(x, y)` covers the area from
+/// `(x - 0.5, y - 0.5)` to `(x + 0.5, y + 0.5)`.
+pub(super) fn coverage<F>(
+    polygons: &[Vec<Pt<f64>>],
+    rule: FillRule,
+    (width, height): (u32, u32),
+    mut f: F,
+) where
+    F: FnMut(u32, u32, f32),
+{
+    let mut points = polygons.iter().flatten();
+    let first = match points.next() {
+        Some(&first) => first,
+        None => return,
+    };
+    let (min, max) = points.fold((first, first), |(min, max), p| {
+        (
+            Pt::new(min.x().min(p.x()), min.y().min(p.y())),
+            Pt::new(max.x().max(p.x()), max.y().max(p.y())),
+        )
+    });
+
+    // Only the part of the image that the polygons could cover is used
+    let x0 = (min.x() + 0.5).floor().max(0.0);
+    let y0 = (min.y() + 0.5).floor().max(0.0);
+    let x1 = (max.x() + 0.5).ceil().min(f64::from(width));
+    let y1 = (max.y() + 0.5).ceil().min(f64::from(height));
+    if x0 >= x1 || y0 >= y1 {
+        return;
+    }
+
+    let mut acc = Accumulator::new((x1 - x0) as usize, (y1 - y0) as usize);
+    let offset = Pt::new(0.5 - x0, 0.5 - y0);
+    for points in polygons {
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            acc.edge(a + offset, b + offset);
+        }
+    }
+
+    let (x0, y0) = (x0 as u32, y0 as u32);
+    acc.coverage(rule, |x, y, c| f(x0 + x as u32, y0 + y as u32, c));
+}
+
+/// Fills an antialiased polygon, which may be self-intersecting, using the
+/// given [`FillRule`](super::FillRule).
+///
+/// Each pixel is blended by exactly how much of its area is inside of the
+/// polygon, so edges are smooth at any angle and the points do not have to be
+/// whole numbers.  Where the polygon overlaps itself the coverage of pixels
+/// on the overlapping edges is approximate.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::{antialiased_polygon_filled, FillRule};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let star = [(200.0, 20.5), (250.2, 350.0), (30.0, 130.0), (370.0, 130.0), (150.0, 350.0)];
+/// antialiased_polygon_filled(&mut image, star, FillRule::NonZero, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_polygon_filled`](crate::Draw::antialiased_polygon_filled)
+///
+pub fn antialiased_polygon_filled<P, T, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    rule: FillRule,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+    It: IntoIterator<Item = P>,
+{
+    use crate::ops::blend_at_unchecked;
+
+    let points: Vec<Pt<f64>> = points
+        .into_iter()
+        .map(|p| Pt::new(p.x().into(), p.y().into()))
+        .collect();
+    let size = image.dimensions();
+
+    coverage(&[points], rule, size, |x, y, c| {
+        // This is safe because only pixels inside of the image are covered
+        unsafe {
+            blend_at_unchecked(image, x, y, c, color);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coverage, FillRule};
+    use crate::Pt;
+
+    fn covered(points: &[(f64, f64)], rule: FillRule, size: (u32, u32)) -> Vec<f32> {
+        let points = points.iter().map(|&(x, y)| Pt::new(x, y)).collect();
+        let mut cells = vec![0.0; (size.0 * size.1) as usize];
+        coverage(&[points], rule, size, |x, y, c| {
+            cells[(y * size.0 + x) as usize] = c;
+        });
+        cells
+    }
+
+    /// Area of a polygon using the shoelace formula
+    fn area(points: &[(f64, f64)]) -> f64 {
+        let sum: f64 = (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                a.0 * b.1 - b.0 * a.1
+            })
+            .sum();
+        sum.abs() / 2.0
+    }
+
+    #[test]
+    fn antialiased_polygons() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let star = [
+            (200.0, 20.0),
+            (250.0, 350.0),
+            (30.0, 130.0),
+            (370.0, 130.0),
+            (150.0, 350.0),
+        ];
+        let half = star.map(|(x, y)| (x / 2.0 + 0.3, y / 2.0 + 0.3));
+        super::antialiased_polygon_filled(
+            &mut image,
+            half,
+            FillRule::NonZero,
+            image::Rgba([255, 0, 0, 255]),
+        );
+        let moved = star.map(|(x, y)| (x / 2.0 + 200.7, y / 2.0 + 200.2));
+        super::antialiased_polygon_filled(
+            &mut image,
+            moved,
+            FillRule::EvenOdd,
+            image::Rgba([0, 0, 255, 255]),
+        );
+        image.save("images/antialiased_polygons.png")
+    }
+
+    #[test]
+    fn rectangle_coverage() {
+        // Covers three quarters of pixels 2 and 5, a fifth of the first row
+        // and two fifths of the second row
+        let cells = covered(
+            &[(1.75, 0.3), (5.25, 0.3), (5.25, 0.9), (1.75, 0.9)],
+            FillRule::NonZero,
+            (8, 2),
+        );
+        let expected = [0.0, 0.0, 0.75, 1.0, 1.0, 0.75, 0.0, 0.0];
+        for (x, &e) in expected.iter().enumerate() {
+            assert!((cells[x] - e * 0.2).abs() < 1e-5, "{x}: {}", cells[x]);
+            assert!(
+                (cells[8 + x] - e * 0.4).abs() < 1e-5,
+                "{x}: {}",
+                cells[8 + x]
+            );
+        }
+    }
+
+    #[test]
+    fn coverage_adds_up_to_area() {
+        let shapes: [&[(f64, f64)]; 4] = [
+            &[(3.2, 4.7), (41.9, 10.1), (17.3, 38.6)],
+            &[(10.0, 10.0), (30.5, 12.25), (35.0, 35.0), (8.75, 31.5)],
+            // Shallow and steep slivers
+            &[(1.2, 1.7), (48.3, 6.1), (47.9, 9.4)],
+            &[(2.1, 1.3), (6.4, 48.2), (3.3, 47.6)],
+        ];
+        for shape in shapes {
+            for rule in [FillRule::NonZero, FillRule::EvenOdd] {
+                let sum: f64 = covered(shape, rule, (50, 50))
+                    .iter()
+                    .map(|&c| f64::from(c))
+                    .sum();
+                assert!((sum - area(shape)).abs() < 1e-2, "{sum} {}", area(shape));
+            }
+        }
+    }
+
+    #[test]
+    fn coverage_clipped() {
+        // Only the part inside of the image is covered
+        let cells = covered(
+            &[(-10.0, -10.0), (20.0, -10.0), (20.0, 4.5), (-10.0, 4.5)],
+            FillRule::NonZero,
+            (10, 10),
+        );
+        for (i, &c) in cells.iter().enumerate() {
+            let expected = if i < 50 { 1.0 } else { 0.0 };
+            assert!((c - expected).abs() < 1e-5, "{i}: {c}");
+        }
+    }
+
+    #[test]
+    fn coverage_rules() {
+        let star = [
+            (20.0, 2.0),
+            (25.0, 35.0),
+            (3.0, 13.0),
+            (37.0, 13.0),
+            (15.0, 35.0),
+        ];
+        let center = 20 * 40 + 20;
+        assert!((covered(&star, FillRule::NonZero, (40, 40))[center] - 1.0).abs() < 1e-5);
+        assert!(covered(&star, FillRule::EvenOdd, (40, 40))[center].abs() < 1e-5);
+    }
+}
@@ -0,0 +1,705 @@
+use super::FillRule;
+use crate::conics::Arc;
+use crate::curves::{flatten, Bezier};
+use crate::lines::{polyline_stroke, LineCap, LineJoin};
+use crate::{angle, Angle, Point, Pt};
+use image::GenericImage;
+
+/// A piece of a [`Path`]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+enum Segment {
+    /// A straight line between two points
+    Line(Pt<f64>, Pt<f64>),
+    /// A cubic Bézier curve, quadratic curves are raised to cubic
+    Curve([Pt<f64>; 4]),
+    /// A circular arc, with angles in radians
+    Arc {
+        start: f64,
+        end: f64,
+        radius: f64,
+        center: Pt<f64>,
+    },
+}
+
+impl Segment {
+    /// Where the segment ends
+    fn end(&self) -> Pt<f64> {
+        match *self {
+            Self::Line(_, b) => b,
+            Self::Curve(ctrl) => ctrl[3],
+            Self::Arc {
+                end,
+                radius,
+                center,
+                ..
+            } => Pt::from_radian(end, radius, center),
+        }
+    }
+
+    /// Points along the segment that are at most 2px apart, after its first
+    /// point.
+    fn flatten(&self, points: &mut Vec<Pt<f64>>) {
+        match self {
+            Self::Line(_, b) => points.push(*b),
+            Self::Curve(ctrl) => points.extend(flatten(ctrl)),
+            &Self::Arc {
+                start,
+                end,
+                radius,
+                center,
+            } => {
+                let sweep = arc_sweep(start, end);
+                let n = (radius * sweep / 2.0).ceil().max(1.0) as u32;
+                points.extend((1..=n).map(|i| {
+                    let angle = start + sweep * f64::from(i) / f64::from(n);
+                    Pt::from_radian(angle, radius, center)
+                }));
+            }
+        }
+    }
+}
+
+/// Radians from `start` to `end`, going counter-clockwise.  Equal angles are
+/// a full circle, like [`Arc`].
+fn arc_sweep(start: f64, end: f64) -> f64 {
+    let sweep = angle::normalize(end - start);
+    if sweep > 0.0 {
+        sweep
+    } else {
+        crate::PI2
+    }
+}
+
+/// Connected segments of a [`Path`]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+struct Subpath {
+    start: Pt<f64>,
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+impl Subpath {
+    /// The point the next segment starts from
+    fn end(&self) -> Pt<f64> {
+        self.segments.last().map_or(self.start, Segment::end)
+    }
+
+    /// Points along the subpath that are at most 2px apart.  Closed subpaths
+    /// do not repeat their start point at the end.
+    fn points(&self) -> Vec<Pt<f64>> {
+        let mut points = vec![self.start];
+        for seg in &self.segments {
+            seg.flatten(&mut points);
+        }
+        points.dedup_by(|a, b| near(*a, *b));
+        if self.closed && points.len() > 1 && near(points[0], points[points.len() - 1]) {
+            points.pop();
+        }
+        points
+    }
+}
+
+/// An outline made of lines, curves and arcs, like the paths of SVG and the
+/// HTML canvas.
+///
+/// A path is built one command at a time, starting from a point set with
+/// [`move_to`](Path::move_to).  Each command continues from where the last
+/// one ended.  Drawing commands that come before the first `move_to` start
+/// from their first point instead.
+///
+/// A path can be stroked with 1px lines, thick lines, or antialiased lines,
+/// or it can be filled.  Paths can also be parsed from SVG path data with
+/// [`Path::from_svg`].
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::Path;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // A rounded rectangle
+/// let path = Path::new()
+///     .move_to((340, 20))
+///     .line_to((60, 20))
+///     .arc_to(90, 180, 40, (60, 60))
+///     .line_to((20, 340))
+///     .arc_to(180, 270, 40, (60, 340))
+///     .line_to((340, 380))
+///     .arc_to(270, 360, 40, (340, 340))
+///     .line_to((380, 60))
+///     .arc_to(0, 90, 40, (340, 60))
+///     .close();
+///
+/// path.fill(&mut image, Rgba([255, 200, 200, 255]));
+/// path.stroke(&mut image, Rgba([255, 0, 0, 255]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default)]
+pub struct Path {
+    subpaths: Vec<Subpath>,
+}
+
+impl Path {
+    /// Creates an empty path.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new subpath at `pt` without connecting it to the last point.
+    #[must_use]
+    pub fn move_to<P, T>(mut self, pt: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        self.subpaths.push(Subpath {
+            start: to_f64(&pt),
+            segments: Vec::new(),
+            closed: false,
+        });
+        self
+    }
+
+    /// Adds a straight line from the last point to `pt`.
+    #[must_use]
+    pub fn line_to<P, T>(mut self, pt: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let pt = to_f64(&pt);
+        let sub = self.subpath(pt);
+        let a = sub.end();
+        sub.segments.push(Segment::Line(a, pt));
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the last point to `pt` with the
+    /// control point `c`.
+    #[must_use]
+    pub fn quad_to<P, T>(mut self, c: P, pt: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (c, pt) = (to_f64(&c), to_f64(&pt));
+        let sub = self.subpath(c);
+        let a = sub.end();
+        // The same curve as a cubic, with both control points 2/3 of the way to c
+        let c1 = a + (c - a).mul(2.0 / 3.0);
+        let c2 = pt + (c - pt).mul(2.0 / 3.0);
+        sub.segments.push(Segment::Curve([a, c1, c2, pt]));
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the last point to `pt` with the control
+    /// points `c1` and `c2`.
+    #[must_use]
+    pub fn cubic_to<P, T>(mut self, c1: P, c2: P, pt: P) -> Self
+    where
+        P: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (c1, c2, pt) = (to_f64(&c1), to_f64(&c2), to_f64(&pt));
+        let sub = self.subpath(c1);
+        let a = sub.end();
+        sub.segments.push(Segment::Curve([a, c1, c2, pt]));
+        self
+    }
+
+    /// Adds a circular arc from `start_angle` to `end_angle`, going
+    /// counter-clockwise like [`Arc`].
+    ///
+    /// A straight line is added from the last point to the start of the arc
+    /// and the path continues from the end of the arc.
+    ///
+    /// Floating-point angles will represent an angle in radians.  Integer types
+    /// will represent an angle in degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radius is less than or equal to 0
+    #[must_use]
+    pub fn arc_to<A, C, T>(mut self, start_angle: A, end_angle: A, radius: T, center: C) -> Self
+    where
+        A: Angle,
+        C: Point<T>,
+        T: Into<f64> + Copy,
+    {
+        let (start, end) = (start_angle.radians(), end_angle.radians());
+        let (radius, center) = (radius.into(), to_f64(&center));
+        assert!(
+            radius > 0.0,
+            "Radius must be larger than 0.  radius={radius}"
+        );
+
+        let from = Pt::from_radian(start, radius, center);
+        let sub = self.subpath(from);
+        let a = sub.end();
+        if !near(a, from) {
+            sub.segments.push(Segment::Line(a, from));
+        }
+        sub.segments.push(Segment::Arc {
+            start,
+            end,
+            radius,
+            center,
+        });
+        self
+    }
+
+    /// Closes the current subpath with a straight line back to its start.
+    ///
+    /// Commands after `close` continue from the start of the closed subpath.
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        if let Some(sub) = self.subpaths.last_mut() {
+            sub.closed = true;
+        }
+        self
+    }
+
+    /// The subpath to add a segment to.  Starts a new subpath at `pt` if there
+    /// is none, or at the start of the last subpath if it was closed.
+    fn subpath(&mut self, pt: Pt<f64>) -> &mut Subpath {
+        let start = match self.subpaths.last() {
+            Some(sub) if !sub.closed => None,
+            Some(sub) => Some(sub.start),
+            None => Some(pt),
+        };
+        if let Some(start) = start {
+            self.subpaths.push(Subpath {
+                start,
+                segments: Vec::new(),
+                closed: false,
+            });
+        }
+        let last = self.subpaths.len() - 1;
+        &mut self.subpaths[last]
+    }
+
+    /// Draws the outline of the path with 1px lines.
+    ///
+    /// Lines are drawn with [`line`](crate::lines::line), curves with
+    /// [`Bezier`](crate::curves::Bezier) and arcs with [`Arc`].  Points that
+    /// are outside of the image bounds are ignored, and lines are clipped to
+    /// the image before they are drawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the image's height or width is larger than 2,147,483,647
+    ///
+    /// See also: [`Draw::stroke_path`](crate::Draw::stroke_path)
+    ///
+    pub fn stroke<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        for sub in &self.subpaths {
+            for seg in &sub.segments {
+                match *seg {
+                    Segment::Line(a, b) => crate::lines::line(image, a.i32(), b.i32(), color),
+                    Segment::Curve(ctrl) => {
+                        crate::draw_iter(image, Bezier::from_ctrl(ctrl), color);
+                    }
+                    Segment::Arc {
+                        start,
+                        end,
+                        radius,
+                        center,
+                    } => Arc::new(start, end, radius, center).draw(image, color),
+                }
+            }
+            if sub.closed {
+                crate::lines::line(image, sub.end().i32(), sub.start.i32(), color);
+            }
+        }
+    }
+
+    /// Draws the outline of the path with lines that are `width` pixels
+    /// thick.
+    ///
+    /// Lines are drawn with [`thick_line`](crate::lines::thick_line).  Curves
+    /// and arcs are flattened into short lines that are filled together as one
+    /// shape, so no pixel is drawn twice and there are no gaps on the outside
+    /// of a bend.  The corners where one piece of the path meets the next are
+    /// rounded.
+    ///
+    /// See also: [`Draw::thick_stroke_path`](crate::Draw::thick_stroke_path)
+    ///
+    pub fn thick_stroke<I>(&self, image: &mut I, width: u16, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        if width == 0 {
+            return;
+        }
+        let radius = f64::from(width) / 2.0;
+
+        for sub in &self.subpaths {
+            let mut pieces = Vec::new();
+            let mut a = sub.start;
+            for seg in &sub.segments {
+                let mut points = vec![a];
+                seg.flatten(&mut points);
+                a = seg.end();
+                pieces.push(points);
+            }
+            if sub.closed && !near(a, sub.start) {
+                pieces.push(vec![a, sub.start]);
+            }
+
+            for points in &pieces {
+                match points[..] {
+                    [a, b] if !near(a, b) => {
+                        crate::lines::thick_line(image, a.i32(), b.i32(), width, color);
+                    }
+                    [_, _] => {}
+                    _ => thick_curve(image, points, radius, color),
+                }
+            }
+
+            // Fill in the outside of each corner
+            if width > 2 {
+                let mut corners: Vec<_> = pieces.iter().map(|p| p[p.len() - 1]).collect();
+                if !sub.closed || pieces.len() < 2 {
+                    corners.pop();
+                }
+                for pt in corners {
+                    crate::conics::circle_filled(image, radius, pt, color);
+                }
+            }
+        }
+    }
+
+    /// Draws the outline of the path with 1px antialiased lines.
+    ///
+    /// Each subpath is flattened into lines and drawn with
+    /// [`polyline_stroke`], with rounded corners and no caps, so every pixel
+    /// is blended once and curves do not get darker where their lines meet.
+    ///
+    /// See also: [`Draw::antialiased_stroke_path`](crate::Draw::antialiased_stroke_path)
+    ///
+    pub fn antialiased_stroke(&self, image: &mut image::RgbaImage, color: image::Rgba<u8>) {
+        self.antialiased_thick_stroke(image, 1.0, color);
+    }
+
+    /// Draws the outline of the path with antialiased lines that are `width`
+    /// pixels wide.
+    ///
+    /// Each subpath is drawn with [`polyline_stroke`], with rounded corners and
+    /// no caps, so every pixel is blended once.
+    ///
+    /// See also: [`Draw::antialiased_thick_stroke_path`](crate::Draw::antialiased_thick_stroke_path)
+    ///
+    pub fn antialiased_thick_stroke(
+        &self,
+        image: &mut image::RgbaImage,
+        width: f32,
+        color: image::Rgba<u8>,
+    ) {
+        for sub in &self.subpaths {
+            polyline_stroke(
+                image,
+                sub.points(),
+                width,
+                LineJoin::Round,
+                LineCap::Butt,
+                sub.closed,
+                color,
+            );
+        }
+    }
+
+    /// Fills the inside of the path.
+    ///
+    /// Subpaths that are not closed are filled as if they were.  Overlapping
+    /// subpaths use the nonzero winding rule, so a subpath inside of another
+    /// one going the opposite way makes a hole.  A pixel is filled when its
+    /// center is inside of the path.
+    ///
+    /// See also: [`Draw::fill_path`](crate::Draw::fill_path)
+    ///
+    pub fn fill<I>(&self, image: &mut I, color: I::Pixel)
+    where
+        I: GenericImage,
+    {
+        let polygons: Vec<_> = self.subpaths.iter().map(Subpath::points).collect();
+        super::polygon::scanline(
+            &polygons,
+            FillRule::NonZero,
+            image.dimensions(),
+            |y, x0, x1| {
+                for x in x0..x1 {
+                    image.put_pixel(x, y, color);
+                }
+            },
+        );
+    }
+}
+
+/// Whether two points are the same, give or take floating-point error.
+fn near(a: Pt<f64>, b: Pt<f64>) -> bool {
+    (a.x() - b.x()).hypot(a.y() - b.y()) < 1e-9
+}
+
+/// Fills a thick line through each of the points, `half` pixels to each
+/// side, with flat ends.
+///
+/// Each line is a rectangle, and the gaps between them on both sides of every
+/// point are filled with triangles.  They are all filled together so that
+/// pixels where they overlap are only drawn once.
+fn thick_curve<I>(image: &mut I, points: &[Pt<f64>], half: f64, color: I::Pixel)
+where
+    I: GenericImage,
+{
+    // Going the same way around every polygon keeps them from cancelling out
+    let add = |polygons: &mut Vec<Vec<Pt<f64>>>, mut polygon: Vec<Pt<f64>>| {
+        let area: f64 = (0..polygon.len())
+            .map(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                a.x() * b.y() - b.x() * a.y()
+            })
+            .sum();
+        if area < 0.0 {
+            polygon.reverse();
+        }
+        polygons.push(polygon);
+    };
+
+    let mut polygons = Vec::new();
+    let mut prev: Option<Pt<f64>> = None;
+    for w in points.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let d = b - a;
+        let len = d.x().hypot(d.y());
+        if len < 1e-9 {
+            continue;
+        }
+        // Normal to the line, half of the width long
+        let n = Pt::new(-d.y(), d.x()).mul(half / len);
+
+        add(&mut polygons, vec![a + n, b + n, b - n, a - n]);
+        if let Some(p) = prev {
+            add(&mut polygons, vec![a, a + p, a + n]);
+            add(&mut polygons, vec![a, a - p, a - n]);
+        }
+        prev = Some(n);
+    }
+
+    super::polygon::scanline(
+        &polygons,
+        FillRule::NonZero,
+        image.dimensions(),
+        |y, x0, x1| {
+            for x in x0..x1 {
+                image.put_pixel(x, y, color);
+            }
+        },
+    );
+}
+
+fn to_f64<P, T>(pt: &P) -> Pt<f64>
+where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    Pt::new(pt.x().into(), pt.y().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Path;
+    use crate::lines::{polyline_stroke, LineCap, LineJoin};
+
+    fn rounded_rect() -> Path {
+        Path::new()
+            .move_to((340, 20))
+            .line_to((60, 20))
+            .arc_to(90, 180, 40, (60, 60))
+            .line_to((20, 340))
+            .arc_to(180, 270, 40, (60, 340))
+            .line_to((340, 380))
+            .arc_to(270, 360, 40, (340, 340))
+            .line_to((380, 60))
+            .arc_to(0, 90, 40, (340, 60))
+            .close()
+    }
+
+    #[test]
+    fn path() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let shape = Path::new()
+            .move_to((100, 300))
+            .quad_to((200, 100), (300, 300))
+            .cubic_to((250, 380), (150, 220), (100, 300))
+            .close();
+
+        rounded_rect().fill(&mut image, image::Rgba([255, 220, 220, 255]));
+        rounded_rect().stroke(&mut image, image::Rgba([255, 0, 0, 255]));
+        shape.fill(&mut image, image::Rgba([200, 200, 255, 255]));
+        shape.thick_stroke(&mut image, 5, image::Rgba([0, 0, 255, 255]));
+        image.save("images/path.png")
+    }
+
+    #[test]
+    fn antialiased_path() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        rounded_rect().antialiased_stroke(&mut image, image::Rgba([255, 0, 0, 255]));
+        Path::new()
+            .move_to((100.5, 300.0))
+            .quad_to((200.0, 100.0), (300.0, 300.0))
+            .cubic_to((250.0, 380.0), (150.0, 220.0), (100.5, 300.0))
+            .close()
+            .antialiased_thick_stroke(&mut image, 4.0, image::Rgba([0, 0, 255, 255]));
+        image.save("images/antialiased_path.png")
+    }
+
+    #[test]
+    fn antialiased_stroke_blends_once() {
+        // Vertices and the closing point are blended once, like polyline_stroke
+        let red = image::Rgba([255, 0, 0, 255]);
+        let points = [(5.0, 5.0), (30.0, 8.5), (33.0, 30.0), (10.5, 32.0)];
+        let mut path = crate::test::img::blank((40, 40));
+        let mut stroke = crate::test::img::blank((40, 40));
+
+        Path::new()
+            .move_to(points[0])
+            .line_to(points[1])
+            .line_to(points[2])
+            .line_to(points[3])
+            .close()
+            .antialiased_stroke(&mut path, red);
+        polyline_stroke(
+            &mut stroke,
+            points,
+            1.0,
+            LineJoin::Round,
+            LineCap::Butt,
+            true,
+            red,
+        );
+        assert_eq!(path, stroke);
+    }
+
+    #[test]
+    fn path_stroke_lines() {
+        // A path of lines draws the same pixels as the lines themselves
+        let color = image::Luma([255]);
+        let mut image = image::GrayImage::new(40, 40);
+        let mut lines = image::GrayImage::new(40, 40);
+
+        Path::new()
+            .move_to((3, 4))
+            .line_to((35, 10))
+            .line_to((20, 36))
+            .close()
+            .stroke(&mut image, color);
+        crate::lines::line(&mut lines, (3, 4), (35, 10), color);
+        crate::lines::line(&mut lines, (35, 10), (20, 36), color);
+        crate::lines::line(&mut lines, (20, 36), (3, 4), color);
+
+        assert_eq!(image, lines);
+
+        // Lines far outside of the image are clipped instead of walked
+        let mut image = image::GrayImage::new(40, 40);
+        let mut line = image::GrayImage::new(40, 40);
+        Path::new()
+            .move_to((-1_000_000, 0))
+            .line_to((1_000_000, 40))
+            .stroke(&mut image, color);
+        crate::lines::line(&mut line, (-1_000_000, 0), (1_000_000, 40), color);
+        assert_eq!(image, line);
+    }
+
+    #[test]
+    fn path_thick_stroke_curves() {
+        // Curves are as wide as the stroke all the way around, without gaps
+        let color = image::Luma([255]);
+        for width in [3, 8, 20, 31] {
+            let mut image = image::GrayImage::new(120, 120);
+            Path::new()
+                .arc_to(0, 180, 35.0, (60.0, 60.0))
+                .cubic_to((10.0, 100.0), (110.0, 100.0), (95.0, 60.0))
+                .close()
+                .thick_stroke(&mut image, width, color);
+
+            let half = f64::from(width) / 2.0;
+            for (x, y, p) in image.enumerate_pixels() {
+                let d = (f64::from(x) - 60.0).hypot(f64::from(y) - 60.0) - 35.0;
+                if y <= 60 {
+                    if d.abs() < half - 1.0 {
+                        assert_eq!(*p, color, "({x}, {y}) width {width} was not drawn");
+                    } else if d.abs() > half + 1.0 {
+                        assert_ne!(*p, color, "({x}, {y}) width {width} is too far out");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn path_fill() {
+        let color = image::Luma([255]);
+
+        // A square fills every pixel with a center inside of it
+        let mut image = image::GrayImage::new(20, 20);
+        Path::new()
+            .move_to((2, 3))
+            .line_to((12, 3))
+            .line_to((12, 9))
+            .line_to((2, 9))
+            .fill(&mut image, color);
+        for (x, y, p) in image.enumerate_pixels() {
+            let inside = (2..12).contains(&x) && (3..9).contains(&y);
+            assert_eq!(p.0[0] == 255, inside, "({x}, {y})");
+        }
+
+        // A square going the other way inside of another makes a hole
+        let mut image = image::GrayImage::new(20, 20);
+        Path::new()
+            .move_to((0, 0))
+            .line_to((20, 0))
+            .line_to((20, 20))
+            .line_to((0, 20))
+            .close()
+            .move_to((5, 5))
+            .line_to((5, 15))
+            .line_to((15, 15))
+            .line_to((15, 5))
+            .close()
+            .fill(&mut image, color);
+        for (x, y, p) in image.enumerate_pixels() {
+            let hole = (5..15).contains(&x) && (5..15).contains(&y);
+            assert_eq!(p.0[0] == 255, !hole, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn path_commands() {
+        // Commands before move_to start from their first point
+        let path = Path::new().line_to((5, 5)).line_to((10, 5));
+        assert_eq!(path.subpaths.len(), 1);
+        assert_eq!(path.subpaths[0].start, (5.0, 5.0).into());
+
+        // Commands after close continue from the start of the closed subpath
+        let path = Path::new()
+            .move_to((1, 2))
+            .line_to((8, 2))
+            .line_to((8, 9))
+            .close()
+            .line_to((1, 9));
+        assert_eq!(path.subpaths.len(), 2);
+        assert_eq!(path.subpaths[1].start, (1.0, 2.0).into());
+
+        // Arcs are connected to the last point
+        let path = Path::new().move_to((0, 0)).arc_to(0, 90, 10, (20, 20));
+        assert_eq!(path.subpaths[0].segments.len(), 2);
+        let end = path.subpaths[0].end();
+        assert!((end.x() - 20.0).abs() < 1e-9 && (end.y() - 10.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,430 @@
+use crate::lines::LineIter;
+use crate::ops::BlendOnce;
+use crate::{Point, Pt};
+use image::GenericImage;
+
+/// Decides which parts of a self-intersecting polygon are inside of it.
+///
+/// Every edge that a ray from a point crosses adds 1 to the point's winding
+/// number if the edge goes down and subtracts 1 if it goes up.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points with an odd winding number are inside, so overlapping parts
+    /// alternate between filled and empty.
+    EvenOdd,
+    /// Points with a winding number other than 0 are inside, so overlapping
+    /// parts are only empty if they go around in opposite directions.
+    NonZero,
+}
+
+/// An edge of a polygon in the active edge table
+#[derive(Copy, Clone, Debug)]
+struct Edge {
+    /// First row the edge crosses
+    y0: i64,
+    /// Row after the last row the edge crosses
+    y1: i64,
+    /// Upper end of the edge
+    top: Pt<f64>,
+    /// Change in x from one row to the next
+    dx: f64,
+    /// Where the edge crosses the current row
+    x: f64,
+    /// 1 if the edge goes down, -1 if it goes up
+    winding: i32,
+}
+
+/// Finds the spans of pixels that are inside of the polygons using an active
+/// edge table, and calls `span` with the row and the start and end (exclusive)
+/// of each one.
+///
+/// A pixel is inside when its center is.  Pixels on the left and top edges
+/// are inside and pixels on the right and bottom edges are not, so polygons
+/// that share an edge never share a pixel.  Only spans inside of a `width` by
+/// `height` image are found.
+pub(super) fn scanline<F>(
+    polygons: &[Vec<Pt<f64>>],
+    rule: FillRule,
+    (width, height): (u32, u32),
+    mut span: F,
+) where
+    F: FnMut(u32, u32, u32),
+{
+    let mut edges = Vec::new();
+    for points in polygons {
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            let (top, bottom, winding) = if a.y() < b.y() { (a, b, 1) } else { (b, a, -1) };
+
+            let (y0, y1) = (top.y().ceil() as i64, bottom.y().ceil() as i64);
+            // Edges that do not cross a row, like horizontal edges, are skipped
+            if y0 < y1 {
+                let dx = (bottom.x() - top.x()) / (bottom.y() - top.y());
+                edges.push(Edge {
+                    y0,
+                    y1,
+                    top,
+                    dx,
+                    x: 0.0,
+                    winding,
+                });
+            }
+        }
+    }
+    edges.sort_by_key(|e| e.y0);
+
+    let first = edges.first().map_or(0, |e| e.y0.max(0));
+    let last = edges
+        .iter()
+        .map(|e| e.y1)
+        .max()
+        .unwrap_or(0)
+        .min(i64::from(height));
+
+    let mut next = 0;
+    let mut active: Vec<Edge> = Vec::new();
+    for y in first..last {
+        while next < edges.len() && edges[next].y0 <= y {
+            active.push(edges[next]);
+            next += 1;
+        }
+        active.retain(|e| e.y1 > y);
+        // Found from the top of each edge instead of adding dx every row, so
+        // rounding errors do not add up along long edges
+        for edge in &mut active {
+            edge.x = edge.top.x() + (y as f64 - edge.top.y()) * edge.dx;
+        }
+        active.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut winding = 0;
+        for pair in active.windows(2) {
+            winding += pair[0].winding;
+            let inside = match rule {
+                FillRule::EvenOdd => winding % 2 != 0,
+                FillRule::NonZero => winding != 0,
+            };
+            if inside {
+                let x0 = pair[0].x.ceil().max(0.0);
+                let x1 = pair[1].x.ceil().min(f64::from(width));
+                if x0 < x1 {
+                    span(y as u32, x0 as u32, x1 as u32);
+                }
+            }
+        }
+    }
+}
+
+/// Collects the points of a polygon.
+fn collect<P, It>(points: It) -> Vec<Pt<i32>>
+where
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    points.into_iter().map(|p| p.pt()).collect()
+}
+
+/// Draws the outline of a polygon, connecting the last point back to the
+/// first.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::polygon;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+/// polygon(&mut image, star, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::polygon`](crate::Draw::polygon)
+///
+pub fn polygon<I, P, It>(image: &mut I, points: It, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let mut points = collect(points);
+    if let Some(&first) = points.first() {
+        points.push(first);
+    }
+    crate::lines::path(image, points, color);
+}
+
+/// Draws the outline of a polygon with the specified opacity, connecting the
+/// last point back to the first.
+///
+/// Pixels shared by neighboring edges are only blended once.
+///
+/// # Panics
+///
+/// - Panics if opacity is not in the range `0.0..=1.0`.
+/// - Panics if the image's height or width is larger than 2,147,483,647
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::polygon_alpha;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+/// polygon_alpha(&mut image, star, 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::polygon_alpha`](crate::Draw::polygon_alpha)
+///
+pub fn polygon_alpha<P, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    check_opacity!(opacity);
+
+    let points = collect(points);
+    let first = match points.first() {
+        Some(&first) => Pt::<f64>::from(first),
+        None => return,
+    };
+    let bounds = points.iter().fold((first, first), |(min, max), &p| {
+        let p = Pt::<f64>::from(p);
+        (
+            Pt::new(min.x().min(p.x()), min.y().min(p.y())),
+            Pt::new(max.x().max(p.x()), max.y().max(p.y())),
+        )
+    });
+
+    check_img_i32!(image);
+
+    #[allow(clippy::cast_possible_wrap)]
+    let max = Pt::new(image.width() as i32 - 1, image.height() as i32 - 1);
+
+    let mut blend = BlendOnce::within(image, bounds, opacity, color);
+    for (i, &a) in points.iter().enumerate() {
+        let mut line = LineIter::new(a, points[(i + 1) % points.len()]);
+        line.clip(Pt::new(0, 0), max);
+        for pt in line {
+            blend.blend(pt);
+        }
+    }
+}
+
+/// Fills a polygon, which may be self-intersecting, using the given
+/// [`FillRule`].
+///
+/// A pixel is filled when its center is inside of the polygon.  Pixels on the
+/// right and bottom edges are left out so polygons that share an edge do not
+/// overlap; use [`polygon`] to draw the edges as well.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::{polygon_filled, FillRule};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// // The center of the star is empty with the even-odd rule
+/// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+/// polygon_filled(&mut image, star, FillRule::EvenOdd, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::polygon_filled`](crate::Draw::polygon_filled)
+///
+pub fn polygon_filled<I, P, It>(image: &mut I, points: It, rule: FillRule, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    let points: Vec<Pt<f64>> = collect(points).into_iter().map(Pt::<f64>::from).collect();
+    let size = image.dimensions();
+
+    scanline(&[points], rule, size, |y, x0, x1| {
+        for x in x0..x1 {
+            // This is safe because the spans are inside of the image
+            unsafe {
+                image.unsafe_put_pixel(x, y, color);
+            }
+        }
+    });
+}
+
+/// Fills a polygon, which may be self-intersecting, with the specified
+/// opacity using the given [`FillRule`].
+///
+/// See [`polygon_filled`].  Every pixel is blended once.
+///
+/// # Panics
+///
+/// Panics if opacity is not in the range `0.0..=1.0`.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::{polygon_filled_alpha, FillRule};
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let star = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+/// polygon_filled_alpha(&mut image, star, FillRule::NonZero, 0.5, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::polygon_filled_alpha`](crate::Draw::polygon_filled_alpha)
+///
+pub fn polygon_filled_alpha<P, It>(
+    image: &mut image::RgbaImage,
+    points: It,
+    rule: FillRule,
+    opacity: f32,
+    color: image::Rgba<u8>,
+) where
+    P: Point<i32>,
+    It: IntoIterator<Item = P>,
+{
+    use crate::ops::blend_at_unchecked;
+
+    check_opacity!(opacity);
+
+    let points: Vec<Pt<f64>> = collect(points).into_iter().map(Pt::<f64>::from).collect();
+    let size = image.dimensions();
+
+    scanline(&[points], rule, size, |y, x0, x1| {
+        for x in x0..x1 {
+            // This is safe because the spans are inside of the image
+            unsafe {
+                blend_at_unchecked(image, x, y, opacity, color);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FillRule;
+    use crate::Pt;
+
+    const STAR: [(i32, i32); 5] = [(200, 20), (250, 350), (30, 130), (370, 130), (150, 350)];
+
+    /// The winding number of a point, found from the edges to its left
+    fn winding(points: &[(i32, i32)], x: f64, y: f64) -> i32 {
+        let mut winding = 0;
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            let (a, b) = (Pt::<f64>::from(Pt::from(a)), Pt::<f64>::from(Pt::from(b)));
+            let down = a.y() <= y && y < b.y();
+            let up = b.y() <= y && y < a.y();
+            if down || up {
+                let (top, bottom) = if down { (a, b) } else { (b, a) };
+                let dx = (bottom.x() - top.x()) / (bottom.y() - top.y());
+                if top.x() + (y - top.y()) * dx <= x {
+                    winding += if down { 1 } else { -1 };
+                }
+            }
+        }
+        winding
+    }
+
+    #[test]
+    fn polygons() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        let shifted = STAR.map(|(x, y)| (x / 2 + 200, y / 2 + 200));
+        super::polygon_filled(
+            &mut image,
+            STAR,
+            FillRule::EvenOdd,
+            image::Rgba([255, 200, 200, 255]),
+        );
+        super::polygon(&mut image, STAR, image::Rgba([255, 0, 0, 255]));
+        super::polygon_filled_alpha(
+            &mut image,
+            shifted,
+            FillRule::NonZero,
+            0.5,
+            image::Rgba([0, 0, 255, 255]),
+        );
+        super::polygon_alpha(&mut image, shifted, 0.8, image::Rgba([0, 0, 255, 255]));
+        image.save("images/polygons.png")
+    }
+
+    #[test]
+    fn polygon_filled_rules() {
+        let color = image::Luma([255]);
+        for shape in [
+            STAR.to_vec(),
+            // Goes around twice
+            vec![
+                (10, 10),
+                (390, 10),
+                (390, 390),
+                (10, 390),
+                (10, 20),
+                (380, 20),
+                (380, 380),
+                (20, 380),
+                (20, 10),
+            ],
+            // Off the edges of the image
+            vec![(-100, 200), (200, -300), (500, 250), (150, 600)],
+        ] {
+            for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+                let mut image = image::GrayImage::new(400, 400);
+                super::polygon_filled(&mut image, shape.iter().copied(), rule, color);
+                for (x, y, p) in image.enumerate_pixels() {
+                    let w = winding(&shape, f64::from(x), f64::from(y));
+                    let inside = match rule {
+                        FillRule::EvenOdd => w % 2 != 0,
+                        FillRule::NonZero => w != 0,
+                    };
+                    assert_eq!(p.0[0] == 255, inside, "{rule:?} ({x}, {y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_alpha_clipped() {
+        // Edges far outside of the image are clipped instead of walked
+        let mut image = crate::test::img::blank((40, 40));
+        let red = image::Rgba([255, 0, 0, 255]);
+        let points = [(-1_000_000, 5), (1_000_000, 30), (1_000_000, 2_000_000)];
+        super::polygon_alpha(&mut image, points, 0.5, red);
+
+        let mut expected = crate::test::img::blank((40, 40));
+        crate::lines::line_alpha(&mut expected, points[0], points[1], 0.5, red);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn polygon_shared_edges() {
+        // Triangles that share an edge do not overlap or leave gaps
+        let color = image::Luma([255]);
+        let mut quad = image::GrayImage::new(40, 40);
+        super::polygon_filled(
+            &mut quad,
+            [(2, 2), (37, 5), (35, 38), (9, 36)],
+            FillRule::NonZero,
+            color,
+        );
+
+        let mut images = [image::GrayImage::new(40, 40), image::GrayImage::new(40, 40)];
+        let tris = [[(2, 2), (37, 5), (9, 36)], [(37, 5), (35, 38), (9, 36)]];
+        for (image, tri) in images.iter_mut().zip(tris) {
+            super::polygon_filled(image, tri, FillRule::NonZero, color);
+        }
+
+        for ((a, b), q) in images[0]
+            .pixels()
+            .zip(images[1].pixels())
+            .zip(quad.pixels())
+        {
+            assert!(!(a.0[0] == 255 && b.0[0] == 255));
+            assert_eq!(a.0[0] == 255 || b.0[0] == 255, q.0[0] == 255);
+        }
+    }
+}
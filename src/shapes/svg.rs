@@ -0,0 +1,625 @@
+use super::Path;
+use crate::Pt;
+
+/// What went wrong while parsing SVG path data.  See [`PathParseError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathParseErrorKind {
+    /// The path data did not start with a move command (`M` or `m`)
+    MissingMoveTo,
+    /// A character that is not a command, number, or separator
+    UnexpectedChar(char),
+    /// A command needed more numbers
+    ExpectedNumber,
+    /// An arc needed a flag (`0` or `1`)
+    ExpectedFlag,
+}
+
+/// An error from parsing SVG path data with [`Path::from_svg`].
+///
+/// Contains the byte position in the path data where parsing failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PathParseError {
+    position: usize,
+    kind: PathParseErrorKind,
+}
+
+impl PathParseError {
+    /// The byte position in the path data where parsing failed.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// What went wrong.
+    #[must_use]
+    pub fn kind(&self) -> PathParseErrorKind {
+        self.kind
+    }
+}
+
+impl std::fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            PathParseErrorKind::MissingMoveTo => write!(
+                f,
+                "path data must start with a move command (M or m) at position {}",
+                self.position
+            ),
+            PathParseErrorKind::UnexpectedChar(c) => {
+                write!(
+                    f,
+                    "unexpected character {:?} at position {}",
+                    c, self.position
+                )
+            }
+            PathParseErrorKind::ExpectedNumber => {
+                write!(f, "expected a number at position {}", self.position)
+            }
+            PathParseErrorKind::ExpectedFlag => {
+                write!(f, "expected a flag (0 or 1) at position {}", self.position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathParseError {}
+
+/// Reads numbers and flags from SVG path data.
+struct Parser<'a> {
+    data: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.data[self.pos..].chars().next()
+    }
+
+    fn error(&self, kind: PathParseErrorKind) -> PathParseError {
+        PathParseError {
+            position: self.pos,
+            kind,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(char::is_ascii_whitespace) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespace with at most one comma
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(',') {
+            self.pos += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    /// Whether a number comes next
+    fn at_number(&self) -> bool {
+        matches!(self.peek(), Some(c) if c.is_ascii_digit() || matches!(c, '+' | '-' | '.'))
+    }
+
+    fn number(&mut self) -> Result<f64, PathParseError> {
+        self.skip_separator();
+        let bytes = self.data.as_bytes();
+        let start = self.pos;
+        let digits = |mut i: usize| {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            i
+        };
+
+        let mut end = start;
+        if end < bytes.len() && matches!(bytes[end], b'+' | b'-') {
+            end += 1;
+        }
+        let int_end = digits(end);
+        let mut mantissa = int_end > end;
+        end = int_end;
+        if end < bytes.len() && bytes[end] == b'.' {
+            let frac_end = digits(end + 1);
+            mantissa |= frac_end > end + 1;
+            end = frac_end;
+        }
+        if !mantissa {
+            return Err(self.error(PathParseErrorKind::ExpectedNumber));
+        }
+        if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            let mut exp = end + 1;
+            if exp < bytes.len() && matches!(bytes[exp], b'+' | b'-') {
+                exp += 1;
+            }
+            let exp_end = digits(exp);
+            if exp_end > exp {
+                end = exp_end;
+            }
+        }
+
+        let n = self.data[start..end]
+            .parse()
+            .map_err(|_| self.error(PathParseErrorKind::ExpectedNumber))?;
+        self.pos = end;
+        Ok(n)
+    }
+
+    /// A point, made of two numbers
+    fn point(&mut self) -> Result<Pt<f64>, PathParseError> {
+        Ok(Pt::new(self.number()?, self.number()?))
+    }
+
+    /// An arc flag, which does not need a separator after it
+    fn flag(&mut self) -> Result<bool, PathParseError> {
+        self.skip_separator();
+        let flag = match self.peek() {
+            Some('0') => false,
+            Some('1') => true,
+            _ => return Err(self.error(PathParseErrorKind::ExpectedFlag)),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+}
+
+impl Path {
+    /// Parses SVG path data, the mini-language used in the `d` attribute of
+    /// SVG `<path>` elements.
+    ///
+    /// All of the commands are supported, in both their absolute (uppercase)
+    /// and relative (lowercase) forms: move (`M`), line (`L`, `H`, `V`),
+    /// cubic Bézier (`C`, `S`), quadratic Bézier (`Q`, `T`), elliptical arc
+    /// (`A`), and close (`Z`).  Elliptical arcs are added to the path as cubic
+    /// Bézier curves.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathParseError`] with the position of the problem if the
+    /// path data is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use image::{RgbaImage, Rgba};
+    /// use freehand::shapes::Path;
+    /// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+    ///
+    /// // A heart
+    /// let heart = Path::from_svg(
+    ///     "M 200,350 L 60,200 A 75,75 0 0 1 200,100 A 75,75 0 0 1 340,200 Z",
+    /// )?;
+    /// heart.fill(&mut image, Rgba([255, 0, 0, 255]));
+    ///
+    /// assert!(Path::from_svg("M 10,10 L 20").is_err());
+    /// # Ok::<(), freehand::shapes::PathParseError>(())
+    /// ```
+    pub fn from_svg(data: &str) -> Result<Self, PathParseError> {
+        use PathParseErrorKind::{ExpectedNumber, MissingMoveTo, UnexpectedChar};
+
+        let mut p = Parser { data, pos: 0 };
+        let mut svg = Svg {
+            path: Self::new(),
+            cur: Pt::new(0.0, 0.0),
+            start: Pt::new(0.0, 0.0),
+            ctrl: None,
+        };
+        // The command being repeated
+        let mut cmd: Option<char> = None;
+
+        loop {
+            p.skip_whitespace();
+            let c = match p.peek() {
+                Some(c) => c,
+                None => break,
+            };
+
+            if c.is_ascii_alphabetic() {
+                if cmd.is_none() && !matches!(c, 'M' | 'm') {
+                    return Err(p.error(MissingMoveTo));
+                }
+                if !"MmLlHhVvCcSsQqTtAaZz".contains(c) {
+                    return Err(p.error(UnexpectedChar(c)));
+                }
+                p.pos += 1;
+                cmd = Some(c);
+            } else {
+                match cmd {
+                    None if p.at_number() => return Err(p.error(MissingMoveTo)),
+                    Some(cmd) if !matches!(cmd, 'Z' | 'z') && (p.at_number() || c == ',') => {
+                        // Numbers after the last set repeat the command, and
+                        // may be separated from it by a comma
+                        p.skip_separator();
+                        if !p.at_number() {
+                            return Err(p.error(ExpectedNumber));
+                        }
+                    }
+                    _ => return Err(p.error(UnexpectedChar(c))),
+                }
+            }
+
+            if let Some(c) = cmd {
+                svg.command(c, &mut p)?;
+                // Extra points after a move are lines
+                cmd = match c {
+                    'M' => Some('L'),
+                    'm' => Some('l'),
+                    c => Some(c),
+                };
+            }
+        }
+
+        Ok(svg.path)
+    }
+}
+
+/// The path being parsed and where it is.
+struct Svg {
+    path: Path,
+    /// The current point
+    cur: Pt<f64>,
+    /// Start of the current subpath
+    start: Pt<f64>,
+    /// The last control point of a C, S, Q, or T command, for reflecting
+    ctrl: Option<(char, Pt<f64>)>,
+}
+
+impl Svg {
+    /// Reads the arguments of a command and adds it to the path.
+    fn command(&mut self, cmd: char, p: &mut Parser<'_>) -> Result<(), PathParseError> {
+        // Relative commands are offset from the current point
+        let base = if cmd.is_ascii_lowercase() {
+            self.cur
+        } else {
+            Pt::new(0.0, 0.0)
+        };
+        let path = std::mem::take(&mut self.path);
+        let mut ctrl = None;
+
+        let (path, end) = match cmd.to_ascii_uppercase() {
+            'M' => {
+                let end = base + p.point()?;
+                self.start = end;
+                (path.move_to(end), end)
+            }
+            'L' => {
+                let end = base + p.point()?;
+                (path.line_to(end), end)
+            }
+            'H' => {
+                let end = Pt::new(base.x() + p.number()?, self.cur.y());
+                (path.line_to(end), end)
+            }
+            'V' => {
+                let end = Pt::new(self.cur.x(), base.y() + p.number()?);
+                (path.line_to(end), end)
+            }
+            'C' | 'S' => {
+                let c1 = if cmd.eq_ignore_ascii_case(&'C') {
+                    base + p.point()?
+                } else {
+                    self.reflect('C')
+                };
+                let c2 = base + p.point()?;
+                let end = base + p.point()?;
+                ctrl = Some(('C', c2));
+                (path.cubic_to(c1, c2, end), end)
+            }
+            'Q' | 'T' => {
+                let c1 = if cmd.eq_ignore_ascii_case(&'Q') {
+                    base + p.point()?
+                } else {
+                    self.reflect('Q')
+                };
+                let end = base + p.point()?;
+                ctrl = Some(('Q', c1));
+                (path.quad_to(c1, end), end)
+            }
+            'A' => {
+                let radii = Pt::new(p.number()?.abs(), p.number()?.abs());
+                let rotation = p.number()?.to_radians();
+                let large = p.flag()?;
+                let sweep = p.flag()?;
+                let end = base + p.point()?;
+                let arc = elliptical_arc(path, self.cur, end, radii, rotation, large, sweep);
+                (arc, end)
+            }
+            _ => (path.close(), self.start),
+        };
+
+        self.path = path;
+        self.cur = end;
+        self.ctrl = ctrl;
+        Ok(())
+    }
+
+    /// The first control point of a smooth curve: the last control point of
+    /// the previous curve of the same `kind` reflected around the current
+    /// point.
+    fn reflect(&self, kind: char) -> Pt<f64> {
+        match self.ctrl {
+            Some((k, c)) if k == kind => self.cur + (self.cur - c),
+            _ => self.cur,
+        }
+    }
+}
+
+impl std::str::FromStr for Path {
+    type Err = PathParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg(s)
+    }
+}
+
+/// Adds an SVG elliptical arc from `from` to `to` to the path as cubic Bézier
+/// curves that each cover at most 90°.
+// https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes
+fn elliptical_arc(
+    path: Path,
+    from: Pt<f64>,
+    to: Pt<f64>,
+    radii: Pt<f64>,
+    rotation: f64,
+    large: bool,
+    sweep: bool,
+) -> Path {
+    let (mut rx, mut ry) = (radii.x(), radii.y());
+    if rx == 0.0 || ry == 0.0 {
+        return path.line_to(to);
+    }
+    let half = (from - to).div(2.0);
+    if half.x() == 0.0 && half.y() == 0.0 {
+        return path;
+    }
+
+    let (sin, cos) = rotation.sin_cos();
+    let x1 = cos * half.x() + sin * half.y();
+    let y1 = -sin * half.x() + cos * half.y();
+
+    // Scale the radii up if they are too small to reach from one end to the other
+    let lambda = (x1 / rx).powi(2) + (y1 / ry).powi(2);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let (rx2, ry2) = (rx * rx, ry * ry);
+    let num = rx2 * ry2 - rx2 * y1 * y1 - ry2 * x1 * x1;
+    let den = rx2 * y1 * y1 + ry2 * x1 * x1;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large == sweep {
+        coef = -coef;
+    }
+    let cx1 = coef * rx * y1 / ry;
+    let cy1 = -coef * ry * x1 / rx;
+    let mid = (from + to).div(2.0);
+    let center = Pt::new(
+        cos * cx1 - sin * cy1 + mid.x(),
+        sin * cx1 + cos * cy1 + mid.y(),
+    );
+
+    let angle = |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let start = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle(
+        (x1 - cx1) / rx,
+        (y1 - cy1) / ry,
+        (-x1 - cx1) / rx,
+        (-y1 - cy1) / ry,
+    );
+    if sweep && delta < 0.0 {
+        delta += crate::PI2;
+    } else if !sweep && delta > 0.0 {
+        delta -= crate::PI2;
+    }
+
+    // A point on the ellipse and the direction it is heading in
+    let at = |t: f64| {
+        let (s, c) = t.sin_cos();
+        let pt = Pt::new(
+            center.x() + cos * rx * c - sin * ry * s,
+            center.y() + sin * rx * c + cos * ry * s,
+        );
+        let dir = Pt::new(-cos * rx * s - sin * ry * c, -sin * rx * s + cos * ry * c);
+        (pt, dir)
+    };
+
+    let n = (delta.abs() / crate::QUAD).ceil().max(1.0);
+    let step = delta / n;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let mut path = path;
+    for i in 0..n as u32 {
+        let t = start + step * f64::from(i);
+        let (p0, d0) = at(t);
+        let (p1, d1) = at(t + step);
+        // End exactly on `to` so that the next command starts from it
+        let p1 = if i + 1 == n as u32 { to } else { p1 };
+        path = path.cubic_to(p0 + d0.mul(k), p1 - d1.mul(k), p1);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathParseError, PathParseErrorKind};
+    use crate::shapes::Path;
+
+    fn render(path: &Path) -> image::GrayImage {
+        let mut image = image::GrayImage::new(60, 60);
+        path.stroke(&mut image, image::Luma([255]));
+        path.fill(&mut image, image::Luma([128]));
+        image
+    }
+
+    /// Number of pixels that are different when the paths are filled
+    fn diff(a: &Path, b: &Path) -> usize {
+        let mut images = [image::GrayImage::new(60, 60), image::GrayImage::new(60, 60)];
+        a.fill(&mut images[0], image::Luma([255]));
+        b.fill(&mut images[1], image::Luma([255]));
+        let [a, b] = images;
+        a.pixels().zip(b.pixels()).filter(|(a, b)| a != b).count()
+    }
+
+    #[test]
+    fn svg() -> Result<(), Box<dyn std::error::Error>> {
+        let mut image = crate::test::img::blank((400, 400));
+        let heart =
+            Path::from_svg("M 200,350 L 60,200 A 75,75 0 0 1 200,100 A 75,75 0 0 1 340,200 Z")?;
+        heart.fill(&mut image, image::Rgba([255, 200, 200, 255]));
+        heart.antialiased_thick_stroke(&mut image, 3.0, image::Rgba([255, 0, 0, 255]));
+
+        let wave: Path = "M20 60 q 40 -50 80 0 t 80 0 t 80 0 t 80 0 M 20 380 c 50-90 100-90 150 0 s 100 90 150 0"
+            .parse()?;
+        wave.antialiased_stroke(&mut image, image::Rgba([0, 0, 255, 255]));
+
+        let rotated = Path::from_svg("M100 250 a60 30 -30 1 0 120 0 z")?;
+        rotated.thick_stroke(&mut image, 3, image::Rgba([0, 160, 0, 255]));
+        image.save("images/svg.png")?;
+        Ok(())
+    }
+
+    #[test]
+    fn svg_matches_builder() -> Result<(), PathParseError> {
+        for (d, path) in [
+            (
+                "M5 5 L50 5 L50 50 Z",
+                Path::new()
+                    .move_to((5, 5))
+                    .line_to((50, 5))
+                    .line_to((50, 50))
+                    .close(),
+            ),
+            (
+                // Relative commands, implicit lines after a move, H and V
+                "m5,5 45,0 v45 h-20 z",
+                Path::new()
+                    .move_to((5, 5))
+                    .line_to((50, 5))
+                    .line_to((50, 50))
+                    .line_to((30, 50))
+                    .close(),
+            ),
+            (
+                // Numbers without separators
+                "M5-5.5.5.5L10e1-2",
+                Path::new()
+                    .move_to((5.0, -5.5))
+                    .line_to((0.5, 0.5))
+                    .line_to((100.0, -2.0)),
+            ),
+            (
+                "M 5 30 C 5 0 55 0 55 30 S 5 60 5 30",
+                Path::new()
+                    .move_to((5, 30))
+                    .cubic_to((5, 0), (55, 0), (55, 30))
+                    .cubic_to((55, 60), (5, 60), (5, 30)),
+            ),
+            (
+                "M 5 30 Q 30 0 55 30 T 5 30",
+                Path::new()
+                    .move_to((5, 30))
+                    .quad_to((30, 0), (55, 30))
+                    .quad_to((80, 60), (5, 30)),
+            ),
+            (
+                // An S after a line reflects nothing
+                "M 5 5 L 20 5 S 55 55 5 55",
+                Path::new()
+                    .move_to((5, 5))
+                    .line_to((20, 5))
+                    .cubic_to((20, 5), (55, 55), (5, 55)),
+            ),
+            (
+                // Commas between sets of numbers
+                "M 10,10 L 20,20, 30,30",
+                Path::new()
+                    .move_to((10, 10))
+                    .line_to((20, 20))
+                    .line_to((30, 30)),
+            ),
+            (
+                "M 10 10 L 20 20 , 30 30",
+                Path::new()
+                    .move_to((10, 10))
+                    .line_to((20, 20))
+                    .line_to((30, 30)),
+            ),
+        ] {
+            assert_eq!(render(&Path::from_svg(d)?), render(&path), "{d}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn svg_arcs() -> Result<(), PathParseError> {
+        // A circle made of two arcs matches a circle made of one arc
+        let svg = Path::from_svg("M 10 30 A 20 20 0 0 0 50 30 A 20 20 0 0 0 10 30 Z")?;
+        let arc = Path::new().arc_to(180, 180, 20, (30, 30)).close();
+        assert!(diff(&svg, &arc) < 4);
+
+        // Radii that are too small are scaled up and a zero radius is a line
+        let small = Path::from_svg("M 10 30.5 A 1 1 0 0 0 50 30.5")?;
+        let half = Path::new().arc_to(180, 0, 20.0, (30.0, 30.5));
+        assert!(diff(&small, &half) < 4);
+        let line = Path::from_svg("M 10 30 A 0 10 0 0 0 50 30")?;
+        assert_eq!(
+            render(&line),
+            render(&Path::new().move_to((10, 30)).line_to((50, 30)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn svg_errors() {
+        let err = |d: &str| {
+            Path::from_svg(d)
+                .map(|_| ())
+                .map_err(|e| (e.position(), e.kind()))
+        };
+
+        assert_eq!(err(""), Ok(()));
+        assert_eq!(err("L 10 10"), Err((0, PathParseErrorKind::MissingMoveTo)));
+        assert_eq!(err("  10 10"), Err((2, PathParseErrorKind::MissingMoveTo)));
+        assert_eq!(
+            err("M 10 10 L 20"),
+            Err((12, PathParseErrorKind::ExpectedNumber))
+        );
+        assert_eq!(
+            err("M 10 10 L 20 x"),
+            Err((13, PathParseErrorKind::ExpectedNumber))
+        );
+        assert_eq!(
+            err("M 10 10 X 5"),
+            Err((8, PathParseErrorKind::UnexpectedChar('X')))
+        );
+        assert_eq!(
+            err("M 10 10 Z 5 5"),
+            Err((10, PathParseErrorKind::UnexpectedChar('5')))
+        );
+        assert_eq!(
+            err("M 0 0 A 5 5 0 2 0 10 10"),
+            Err((14, PathParseErrorKind::ExpectedFlag))
+        );
+        assert_eq!(err("M 0 0 A 5 5 0 1010 10"), Ok(()));
+        assert_eq!(
+            err("M 10,,10"),
+            Err((5, PathParseErrorKind::ExpectedNumber))
+        );
+        assert_eq!(
+            err("M 10 10 L 20 20,,30 30"),
+            Err((16, PathParseErrorKind::ExpectedNumber))
+        );
+        assert_eq!(
+            err("M 10 10 L 20 20, Z"),
+            Err((17, PathParseErrorKind::ExpectedNumber))
+        );
+        assert_eq!(
+            err("M 10 10 Z, 5 5"),
+            Err((9, PathParseErrorKind::UnexpectedChar(',')))
+        );
+
+        let e = Path::from_svg("M 10 10 L 20").unwrap_err();
+        assert_eq!(e.to_string(), "expected a number at position 12");
+    }
+}
@@ -0,0 +1,204 @@
+use super::FillRule;
+use crate::{Point, Pt};
+use image::GenericImage;
+
+/// Draws the outline of a triangle.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::triangle;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// triangle(&mut image, (200, 20), (370, 350), (30, 300), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::triangle`](crate::Draw::triangle)
+///
+pub fn triangle<I, P>(image: &mut I, a: P, b: P, c: P, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    super::polygon(image, [a, b, c], color);
+}
+
+/// Fills a triangle.
+///
+/// A pixel is filled when its center is inside of the triangle.  Pixels on
+/// the right and bottom edges are left out, so triangles in a mesh that share
+/// an edge never draw the same pixel twice or leave gaps between them.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::triangle_filled;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// triangle_filled(&mut image, (200, 20), (370, 350), (30, 300), Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::triangle_filled`](crate::Draw::triangle_filled)
+///
+pub fn triangle_filled<I, P>(image: &mut I, a: P, b: P, c: P, color: I::Pixel)
+where
+    I: GenericImage,
+    P: Point<i32>,
+{
+    super::polygon_filled(image, [a, b, c], FillRule::NonZero, color);
+}
+
+/// Fills an antialiased triangle, blending each pixel by how much of it is
+/// inside of the triangle.
+///
+/// See [`antialiased_polygon_filled`](super::antialiased_polygon_filled).
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::antialiased_triangle_filled;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let (a, b, c) = ((200.0, 20.5), (370.25, 350.0), (30.0, 300.75));
+/// antialiased_triangle_filled(&mut image, a, b, c, Rgba([255, 0, 0, 255]));
+/// ```
+///
+/// See also: [`Draw::antialiased_triangle_filled`](crate::Draw::antialiased_triangle_filled)
+///
+pub fn antialiased_triangle_filled<P, T>(
+    image: &mut image::RgbaImage,
+    a: P,
+    b: P,
+    c: P,
+    color: image::Rgba<u8>,
+) where
+    P: Point<T>,
+    T: Into<f64> + Copy,
+{
+    super::antialiased_polygon_filled(image, [a, b, c], FillRule::NonZero, color);
+}
+
+/// Fills a triangle with each vertex having its own color, blending the
+/// colors across the triangle (Gouraud shading).
+///
+/// Each pixel's color is found from how close its center is to each vertex
+/// using barycentric coordinates, with all four channels blended.  The
+/// pixels that are filled are the same as with [`triangle_filled`], so
+/// triangles that share an edge fit together without gaps or overlaps.
+///
+/// # Example
+///
+/// ```
+/// # use image::{RgbaImage, Rgba};
+/// use freehand::shapes::gouraud_triangle;
+/// # let mut image = RgbaImage::from_pixel(400, 400, Rgba([255, 255, 255, 255]));
+///
+/// let colors = [Rgba([255, 0, 0, 255]), Rgba([0, 255, 0, 255]), Rgba([0, 0, 255, 255])];
+/// gouraud_triangle(&mut image, (200, 20), (370, 350), (30, 300), colors);
+/// ```
+///
+/// See also: [`Draw::gouraud_triangle`](crate::Draw::gouraud_triangle)
+///
+pub fn gouraud_triangle<P>(
+    image: &mut image::RgbaImage,
+    a: P,
+    b: P,
+    c: P,
+    colors: [image::Rgba<u8>; 3],
+) where
+    P: Point<i32>,
+{
+    let [a, b, c] = [a, b, c].map(|p| Pt::<f64>::from(p.pt()));
+    // Twice the signed area of the triangle p, q, r
+    let cross = |p: Pt<f64>, q: Pt<f64>, r: Pt<f64>| {
+        (q.x() - p.x()) * (r.y() - p.y()) - (r.x() - p.x()) * (q.y() - p.y())
+    };
+    let area = cross(a, b, c);
+    if area.abs() < f64::EPSILON {
+        return;
+    }
+
+    let size = image.dimensions();
+    super::polygon::scanline(&[vec![a, b, c]], FillRule::NonZero, size, |y, x0, x1| {
+        for x in x0..x1 {
+            let p = Pt::new(f64::from(x), f64::from(y));
+            let weights = [cross(p, b, c), cross(a, p, c), cross(a, b, p)].map(|w| w / area);
+
+            let mut color = [0; 4];
+            for (i, channel) in color.iter_mut().enumerate() {
+                let value: f64 = weights
+                    .iter()
+                    .zip(colors)
+                    .map(|(w, c)| w * f64::from(c.0[i]))
+                    .sum();
+                *channel = value.round().clamp(0.0, 255.0) as u8;
+            }
+
+            // This is safe because the spans are inside of the image
+            unsafe {
+                image.unsafe_put_pixel(x, y, image::Rgba(color));
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    const RED: image::Rgba<u8> = image::Rgba([255, 0, 0, 255]);
+    const GREEN: image::Rgba<u8> = image::Rgba([0, 255, 0, 255]);
+    const BLUE: image::Rgba<u8> = image::Rgba([0, 0, 255, 255]);
+
+    #[test]
+    fn triangles() -> Result<(), image::ImageError> {
+        let mut image = crate::test::img::blank((400, 400));
+        super::triangle(&mut image, (20, 20), (180, 60), (60, 180), RED);
+        super::triangle_filled(&mut image, (220, 20), (380, 60), (260, 180), RED);
+        super::antialiased_triangle_filled(
+            &mut image,
+            (20.3, 220.0),
+            (180.0, 260.5),
+            (60.0, 380.2),
+            BLUE,
+        );
+        super::gouraud_triangle(
+            &mut image,
+            (300, 210),
+            (390, 390),
+            (210, 390),
+            [RED, GREEN, BLUE],
+        );
+        image.save("images/triangles.png")
+    }
+
+    #[test]
+    fn gouraud_vertex_colors() {
+        let mut image = image::RgbaImage::new(50, 50);
+        super::gouraud_triangle(&mut image, (0, 0), (40, 0), (0, 40), [RED, GREEN, BLUE]);
+        // Pixels on the top and left edges are filled, so the first vertex is
+        assert_eq!(*image.get_pixel(0, 0), RED);
+        // Halfway between the first and second vertices
+        assert_eq!(*image.get_pixel(20, 0), image::Rgba([128, 128, 0, 255]));
+
+        // The middle of the triangle is an even mix of all three
+        let mut image = image::RgbaImage::new(50, 50);
+        super::gouraud_triangle(&mut image, (0, 0), (42, 0), (0, 42), [RED, GREEN, BLUE]);
+        assert_eq!(*image.get_pixel(14, 14), image::Rgba([85, 85, 85, 255]));
+    }
+
+    #[test]
+    fn gouraud_matches_filled() {
+        // The same pixels are filled as with triangle_filled
+        let (a, b, c) = ((3, 45), (27, 2), (48, 31));
+        let mut filled = image::RgbaImage::new(50, 50);
+        super::triangle_filled(&mut filled, a, b, c, RED);
+        let mut shaded = image::RgbaImage::new(50, 50);
+        super::gouraud_triangle(&mut shaded, a, b, c, [RED, GREEN, BLUE]);
+
+        for (f, s) in filled.pixels().zip(shaded.pixels()) {
+            assert_eq!(f.0[3], s.0[3]);
+        }
+    }
+}
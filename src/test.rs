@@ -48,6 +48,28 @@ where
     None
 }
 
+/// Checks that the pixels drawn in `solid` were each blended exactly once,
+/// at 50% opacity, in `blended` and that no other pixels were changed.
+///
+/// Both images should start out white.
+#[cfg(test)]
+pub(crate) fn assert_blended_once(solid: &image::RgbaImage, blended: &image::RgbaImage) {
+    let white = image::Rgba([255, 255, 255, 255]);
+    let half = image::Rgba([255, 127, 127, 255]);
+    let mut count = 0;
+
+    for ((x, y, s), b) in solid.enumerate_pixels().zip(blended.pixels()) {
+        if *s == white {
+            assert_eq!(*b, white, "({x}, {y}) should not have been blended");
+        } else {
+            assert_eq!(*b, half, "({x}, {y}) was not blended once");
+            count += 1;
+        }
+    }
+
+    assert!(count > 0, "nothing was drawn");
+}
+
 #[cfg(test)]
 // #[macro_export]
 macro_rules! test_pixels_changed {
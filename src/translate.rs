@@ -13,3 +13,10 @@ pub(crate) fn iter_to_real(x: i32, y: i32, oct: u8, c: Pt<i32>) -> Pt<i32> {
         _ => (x + c.x(), y + c.y()).into(),
     }
 }
+
+/// Splits a center into the pixel it falls in and the sub-pixel offset of the
+/// center from that pixel, in the iterator coordinates of the given octant.
+pub(crate) fn center_offset(c: Pt<f64>, oct: u8) -> (Pt<i32>, Pt<f64>) {
+    let pixel = Pt::new(c.x().floor(), c.y().floor());
+    (pixel.i32(), c.real_to_iter(oct, pixel))
+}